@@ -0,0 +1,312 @@
+//! Derive macros for [thirtyfour-testing-library-ext](https://docs.rs/thirtyfour-testing-library-ext).
+//!
+//! Use them through the re-exports of the main crate rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, Meta,
+    PathArguments, Token, Type,
+};
+
+/// Selectors available in a `#[tl(..)]` attribute, named after the `By` constructors
+const SELECTORS: &[&str] = &[
+    "role",
+    "text",
+    "label_text",
+    "placeholder_text",
+    "display_value",
+    "alt_text",
+    "title",
+    "test_id",
+];
+
+/// Derive macro for a thirtyfour `Component` whose fields are resolved with Testing Library queries
+///
+/// Like thirtyfour's `#[derive(Component)]`, the struct needs a base `WebElement` field, named
+/// `base` or marked with `#[base]`, and gets `new(base)`, `From<WebElement>` and `Component`
+/// implementations. Fields marked with `#[tl(..)]` are `ElementResolver`s querying a `Screen`
+/// scoped to the base element, other fields are initialised with `Default::default()`.
+///
+/// `new(base)` and `From<WebElement>` query with the default configure options and no custom
+/// queries, like `TestingLibraryElementExt::screen`. `with_screen(base, &screen)` queries
+/// through `screen` instead, keeping its configure options, custom queries and frames.
+///
+/// ## `#[tl(..)]`
+///
+/// The attribute needs one selector, named after the `By` constructors: `role`, `text`,
+/// `label_text`, `placeholder_text`, `display_value`, `alt_text`, `title` or `test_id`.
+///
+/// Every other `key = value` calls the selector builder method of the same name, and a bare
+/// `key` calls it with `true`, so `#[tl(role = "heading", level = 2, hidden)]` queries
+/// `By::role("heading").level(2).hidden(true)`.
+///
+/// The query is picked from the resolver type:
+/// - `ElementResolver<T>`: `Screen::get`, or `Screen::find` with `wait`. `first` keeps the
+///   first match instead of failing on multiple elements.
+/// - `ElementResolver<Option<T>>`: `Screen::query`.
+/// - `ElementResolver<Vec<T>>`: `Screen::get_all`, or `Screen::find_all` with `wait`.
+///   `allow_empty` uses `Screen::query_all` instead. `multi` forces this mode for type aliases.
+///
+/// `T` is a `WebElement` or any nested component built from one. Nested components are built
+/// with `From<WebElement>`, so they use the default configuration.
+///
+/// ## Example
+/// ```ignore
+/// #[derive(Debug, Clone, TestingLibraryComponent)]
+/// pub struct LoginForm {
+///     base: WebElement,
+///     #[tl(label_text = "Email")]
+///     email: ElementResolver<WebElement>,
+///     #[tl(role = "button", name = "Log in")]
+///     submit: ElementResolver<WebElement>,
+///     #[tl(role = "alert")]
+///     error: ElementResolver<Option<WebElement>>,
+/// }
+/// ```
+#[proc_macro_derive(TestingLibraryComponent, attributes(base, tl))]
+pub fn derive_testing_library_component(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    expand_component(ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_component(ast: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &ast.ident;
+    let fields = match &ast.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "tuple or unit structs are not supported",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "enums and unions are not supported",
+            ))
+        }
+    };
+
+    let mut base_ident = None;
+    let mut field_idents = Vec::new();
+    let mut initialisers = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let cfg_attr = field.attrs.iter().find(|attr| attr.path().is_ident("cfg"));
+
+        if field.attrs.iter().any(|attr| attr.path().is_ident("base")) || field_ident == "base" {
+            if base_ident.is_some() {
+                return Err(syn::Error::new(
+                    field_ident.span(),
+                    "cannot specify multiple base fields",
+                ));
+            }
+            base_ident = Some(field_ident);
+            continue;
+        }
+
+        let value = match field.attrs.iter().find(|attr| attr.path().is_ident("tl")) {
+            Some(attr) => resolver(attr, &field.ty)?,
+            None => quote!(::core::default::Default::default()),
+        };
+        initialisers.push(quote! {
+            #cfg_attr
+            let #field_ident = #value;
+        });
+        field_idents.push(quote! {
+            #cfg_attr
+            #field_ident
+        });
+    }
+
+    let Some(base_ident) = base_ident else {
+        return Err(syn::Error::new(
+            ident.span(),
+            "base field not found. Add the #[base] attribute for the base WebElement field",
+        ));
+    };
+
+    Ok(quote! {
+        impl #ident {
+            pub fn new(base: ::thirtyfour::WebElement) -> Self {
+                let screen = ::thirtyfour_testing_library_ext::TestingLibraryElementExt::screen(&base);
+                Self::with_screen(base, &screen)
+            }
+
+            #[allow(unused_variables)]
+            pub fn with_screen(
+                base: ::thirtyfour::WebElement,
+                screen: &::thirtyfour_testing_library_ext::Screen,
+            ) -> Self {
+                #(#initialisers)*
+                Self {
+                    #base_ident: base,
+                    #(#field_idents,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<::thirtyfour::WebElement> for #ident {
+            fn from(elem: ::thirtyfour::WebElement) -> Self {
+                Self::new(elem)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::thirtyfour::components::Component for #ident {
+            fn base_element(&self) -> ::thirtyfour::WebElement {
+                self.#base_ident.clone()
+            }
+        }
+    })
+}
+
+/// How a field resolves its elements
+#[derive(PartialEq)]
+enum Mode {
+    Single,
+    Optional,
+    Multi,
+}
+
+/// Build the `ElementResolver` for a field from its `#[tl(..)]` attribute
+fn resolver(attr: &Attribute, ty: &Type) -> syn::Result<TokenStream2> {
+    let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+    let mut selector: Option<(Ident, Expr)> = None;
+    let mut options = Vec::new();
+    let (mut single, mut first, mut multi, mut allow_empty, mut wait) =
+        (false, false, false, false, false);
+
+    for meta in metas {
+        match meta {
+            Meta::NameValue(nv) => {
+                let key = nv.path.require_ident()?.clone();
+                if SELECTORS.iter().any(|name| key == name) {
+                    if selector.is_some() {
+                        return Err(syn::Error::new(key.span(), "only one selector is allowed"));
+                    }
+                    selector = Some((key, nv.value));
+                } else {
+                    let value = nv.value;
+                    options.push(quote_spanned!(key.span()=> .#key(#value)));
+                }
+            }
+            Meta::Path(path) => {
+                let key = path.require_ident()?;
+                let flag = match key.to_string().as_str() {
+                    "single" => &mut single,
+                    "first" => &mut first,
+                    "multi" => &mut multi,
+                    "allow_empty" => &mut allow_empty,
+                    "wait" => &mut wait,
+                    _ => {
+                        options.push(quote_spanned!(key.span()=> .#key(true)));
+                        continue;
+                    }
+                };
+                if *flag {
+                    return Err(syn::Error::new(key.span(), format!("duplicate `{key}`")));
+                }
+                *flag = true;
+            }
+            Meta::List(list) => {
+                return Err(syn::Error::new(
+                    list.span(),
+                    "expected `key = value` or `key`",
+                ))
+            }
+        }
+    }
+
+    let Some((selector, value)) = selector else {
+        return Err(syn::Error::new(
+            attr.span(),
+            format!(
+                "missing selector, expected one of: {}",
+                SELECTORS.join(", ")
+            ),
+        ));
+    };
+
+    let mode = match resolver_mode(ty) {
+        Some(Mode::Optional) => Mode::Optional,
+        Some(Mode::Multi) => Mode::Multi,
+        _ if multi => Mode::Multi,
+        _ => Mode::Single,
+    };
+
+    let conflict = match mode {
+        Mode::Single if single && first => Some("`single` and `first` are mutually exclusive"),
+        Mode::Single if multi || allow_empty => {
+            Some("`multi` and `allow_empty` need multiple elements")
+        }
+        Mode::Optional if single || first || multi || wait || allow_empty => {
+            Some("optional elements only support the selector and its options")
+        }
+        Mode::Multi if single || first => Some("`single` and `first` need a single element"),
+        Mode::Multi if allow_empty && wait => {
+            Some("`allow_empty` and `wait` are mutually exclusive")
+        }
+        _ => None,
+    };
+    if let Some(message) = conflict {
+        return Err(syn::Error::new(attr.span(), message));
+    }
+
+    let selector = quote! {
+        ::core::convert::Into::into(::thirtyfour_testing_library_ext::By::#selector(#value)#(#options)*)
+    };
+    let component = quote!(::thirtyfour_testing_library_ext::component);
+
+    Ok(match mode {
+        Mode::Single if first => {
+            quote!(#component::first_resolver(base.clone(), screen.clone(), #selector, #wait))
+        }
+        Mode::Single => {
+            quote!(#component::single_resolver(base.clone(), screen.clone(), #selector, #wait))
+        }
+        Mode::Optional => {
+            quote!(#component::optional_resolver(base.clone(), screen.clone(), #selector))
+        }
+        Mode::Multi => {
+            quote!(#component::multi_resolver(base.clone(), screen.clone(), #selector, #allow_empty, #wait))
+        }
+    })
+}
+
+/// Infer the mode from the resolver type, `None` when the type is not recognised
+fn resolver_mode(ty: &Type) -> Option<Mode> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    match segment.ident.to_string().as_str() {
+        "ElementResolverSingle" => Some(Mode::Single),
+        "ElementResolverMulti" => Some(Mode::Multi),
+        "ElementResolver" => {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            let Some(GenericArgument::Type(Type::Path(inner))) = args.args.first() else {
+                return Some(Mode::Single);
+            };
+            match inner.path.segments.last()?.ident.to_string().as_str() {
+                "Vec" => Some(Mode::Multi),
+                "Option" => Some(Mode::Optional),
+                _ => Some(Mode::Single),
+            }
+        }
+        _ => None,
+    }
+}
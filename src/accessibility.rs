@@ -0,0 +1,6 @@
+/// Script computing the accessible name or description of an element with the
+/// dom-accessibility-api function of the given name.
+pub(crate) const ACCESSIBILITY_SCRIPT: &str = r#"
+    const [compute, element] = arguments;
+    return window.__TL__[compute](element);
+"#;
@@ -0,0 +1,103 @@
+use crate::{By, Screen};
+use thirtyfour::components::ElementResolver;
+use thirtyfour::error::no_such_element;
+use thirtyfour::WebElement;
+
+/// Resolver for the single element matching the selector inside the base element, see `Screen::get`.
+/// The element is queried through `screen` scoped to the base element.
+///
+/// With `wait`, the element is resolved with `Screen::find` instead.
+pub fn single_resolver<T>(
+    base: WebElement,
+    screen: Screen,
+    selector: By,
+    wait: bool,
+) -> ElementResolver<T>
+where
+    T: From<WebElement> + Clone + Send + Sync + 'static,
+{
+    ElementResolver::new_custom(base, move |elem: WebElement| {
+        let (screen, selector) = (screen.within(elem), selector.clone());
+        async move {
+            let element = if wait {
+                screen.find(selector).await?
+            } else {
+                screen.get(selector).await?
+            };
+            Ok(element.into())
+        }
+    })
+}
+
+/// Resolver for the first element matching the selector inside the base element
+///
+/// With `wait`, the elements are resolved with `Screen::find_all` instead of `Screen::get_all`.
+pub fn first_resolver<T>(
+    base: WebElement,
+    screen: Screen,
+    selector: By,
+    wait: bool,
+) -> ElementResolver<T>
+where
+    T: From<WebElement> + Clone + Send + Sync + 'static,
+{
+    ElementResolver::new_custom(base, move |elem: WebElement| {
+        let (screen, selector) = (screen.within(elem), selector.clone());
+        async move {
+            let elements = if wait {
+                screen.find_all(selector).await?
+            } else {
+                screen.get_all(selector).await?
+            };
+            elements
+                .into_iter()
+                .next()
+                .map(T::from)
+                .ok_or_else(|| no_such_element("No element found".to_string()))
+        }
+    })
+}
+
+/// Resolver for the element matching the selector inside the base element, if any, see `Screen::query`
+pub fn optional_resolver<T>(
+    base: WebElement,
+    screen: Screen,
+    selector: By,
+) -> ElementResolver<Option<T>>
+where
+    T: From<WebElement> + Clone + Send + Sync + 'static,
+{
+    ElementResolver::new_custom(base, move |elem: WebElement| {
+        let (screen, selector) = (screen.within(elem), selector.clone());
+        async move { Ok(screen.query(selector).await?.map(T::from)) }
+    })
+}
+
+/// Resolver for all the elements matching the selector inside the base element, see `Screen::get_all`
+///
+/// With `wait`, the elements are resolved with `Screen::find_all` instead. With `allow_empty`,
+/// they are resolved with `Screen::query_all` and no match gives an empty list.
+pub fn multi_resolver<T>(
+    base: WebElement,
+    screen: Screen,
+    selector: By,
+    allow_empty: bool,
+    wait: bool,
+) -> ElementResolver<Vec<T>>
+where
+    T: From<WebElement> + Clone + Send + Sync + 'static,
+{
+    ElementResolver::new_custom(base, move |elem: WebElement| {
+        let (screen, selector) = (screen.within(elem), selector.clone());
+        async move {
+            let elements = if allow_empty {
+                screen.query_all(selector).await?
+            } else if wait {
+                screen.find_all(selector).await?
+            } else {
+                screen.get_all(selector).await?
+            };
+            Ok(elements.into_iter().map(T::from).collect())
+        }
+    })
+}
@@ -49,6 +49,12 @@ pub struct Options {
     /// Defaults to 1000ms.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub async_util_timeout: Option<u64>,
+
+    /// The maximum number of characters printed by `Screen::pretty_dom` and `Screen::debug`,
+    /// like the DEBUG_PRINT_LIMIT environment variable. Not sent to the page.
+    /// Defaults to 7000.
+    #[serde(skip)]
+    pub debug_print_limit: Option<usize>,
 }
 
 impl Default for Options {
@@ -68,6 +74,7 @@ impl Options {
             throw_suggestions: None,
             test_id_attribute: None,
             async_util_timeout: None,
+            debug_print_limit: None,
         }
     }
 
@@ -113,6 +120,12 @@ impl Options {
         self
     }
 
+    /// Builder method to set debug_print_limit
+    pub fn with_debug_print_limit(mut self, value: usize) -> Self {
+        self.debug_print_limit = Some(value);
+        self
+    }
+
     /// Serialize the options to JSON string
     pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
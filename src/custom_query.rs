@@ -0,0 +1,115 @@
+use serde::Serialize;
+
+/// Error messages of a custom query, `{value}` is replaced by the queried value
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryErrorMessages {
+    /// Message of get and find queries matching more than one element
+    pub multiple: String,
+    /// Message of get and find queries matching no element
+    pub missing: String,
+}
+
+impl QueryErrorMessages {
+    /// Create error messages, e.g. `("Found multiple elements with data-cy {value}", "Unable to find an element with data-cy {value}")`
+    pub fn new(multiple: impl Into<String>, missing: impl Into<String>) -> Self {
+        Self {
+            multiple: multiple.into(),
+            missing: missing.into(),
+        }
+    }
+
+    /// Default messages naming the query
+    pub(crate) fn for_query(name: &str) -> Self {
+        Self::new(
+            format!("Found multiple elements by {name}: {{value}}"),
+            format!("Unable to find an element by {name}: {{value}}"),
+        )
+    }
+}
+
+/// Suffixes of the Testing Library queries, custom queries can't be registered under these names
+pub(crate) const BUILT_IN_QUERIES: &[&str] = &[
+    "Role",
+    "Text",
+    "LabelText",
+    "PlaceholderText",
+    "DisplayValue",
+    "AltText",
+    "Title",
+    "TestId",
+];
+
+/// Custom query registered with `Screen::register_query`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CustomQuery {
+    pub(crate) name: String,
+    pub(crate) query_all: String,
+    pub(crate) error_messages: QueryErrorMessages,
+}
+
+/// Script defining `installQuery`, which builds the queries of a `CustomQuery` with Testing Library
+/// `buildQueries` and adds them to `window.__TL__` unless the same query is already there.
+/// Installed queries are kept in `window.__TL__.customQueries` so a changed query replaces the old one.
+/// Multiple matches throw a `TestingLibraryMultipleElementsError` whatever their message.
+pub(crate) const INSTALL_QUERY_SCRIPT: &str = r#"
+    const installQuery = (query) => {
+        if (!query) return;
+        const installed = (window.__TL__.customQueries ||= {});
+        const definition = JSON.stringify(query);
+        if (installed[query.name] === definition) return;
+        const queryAllFunction = new Function("container", "value", "options", query.queryAll);
+        const queryAll = (container, value, options) => Array.from(queryAllFunction(container, value, options || {}));
+        const message = (template, value) => template.replaceAll("{value}", String(value));
+        const multipleError = (_, value) => {
+            const error = new Error(message(query.errorMessages.multiple, value));
+            error.name = "TestingLibraryMultipleElementsError";
+            throw error;
+        };
+        const [queryBy, getAllBy, getBy, findAllBy, findBy] = window.__TL__.buildQueries(
+            queryAll,
+            multipleError,
+            (_, value) => message(query.errorMessages.missing, value),
+        );
+        Object.assign(window.__TL__, {
+            [`queryAllBy${query.name}`]: queryAll,
+            [`queryBy${query.name}`]: queryBy,
+            [`getAllBy${query.name}`]: getAllBy,
+            [`getBy${query.name}`]: getBy,
+            [`findAllBy${query.name}`]: findAllBy,
+            [`findBy${query.name}`]: findBy,
+        });
+        installed[query.name] = definition;
+    };
+"#;
+
+/// Script installing the custom query given as its only argument
+pub(crate) const REGISTER_QUERY_SCRIPT: &str = r#"
+    installQuery(arguments[0]);
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_custom_query_json() {
+        let query = CustomQuery {
+            name: "DataCy".to_string(),
+            query_all: "return container.querySelectorAll(`[data-cy=\"${value}\"]`);".to_string(),
+            error_messages: QueryErrorMessages::for_query("DataCy"),
+        };
+        assert_eq!(
+            serde_json::to_value(query).unwrap(),
+            json!({
+                "name": "DataCy",
+                "queryAll": "return container.querySelectorAll(`[data-cy=\"${value}\"]`);",
+                "errorMessages": {
+                    "multiple": "Found multiple elements by DataCy: {value}",
+                    "missing": "Unable to find an element by DataCy: {value}"
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use serde::Serialize;
+
+/// Number of characters printed by `Screen::pretty_dom` and `Screen::debug` when
+/// neither `PrettyDomOptions::max_length` nor `configure::Options::debug_print_limit` is set
+pub const DEFAULT_DEBUG_PRINT_LIMIT: usize = 7000;
+
+/// Options for `Screen::pretty_dom`, like Testing Library `prettyDOM`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrettyDomOptions {
+    /// Maximum number of characters, defaults to `configure::Options::debug_print_limit`
+    #[serde(skip)]
+    pub max_length: Option<usize>,
+    /// JavaScript function body returning whether `node` is printed,
+    /// defaults to skipping comments, scripts and styles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_node: Option<String>,
+    /// Color the output with ANSI escape codes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<bool>,
+}
+
+impl PrettyDomOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the max_length option
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the filter_node option, e.g. `return node.nodeType === Node.ELEMENT_NODE;`
+    pub fn filter_node(mut self, filter_node: impl Into<String>) -> Self {
+        self.filter_node = Some(filter_node.into());
+        self
+    }
+
+    /// Set the highlight option
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+}
+
+/// Script formatting an element (`null` for the document body) with `prettyDOM`,
+/// given the max length and the prettyDOM options.
+pub(crate) const PRETTY_DOM_SCRIPT: &str = r#"
+    const [element, maxLength, options] = arguments;
+    const { filterNode, ...prettyOptions } = options;
+    if (filterNode) prettyOptions.filterNode = new Function("node", filterNode);
+    return window.__TL__.prettyDOM(element || document.body, maxLength, prettyOptions);
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pretty_dom_options_json() {
+        assert_eq!(
+            serde_json::to_value(PrettyDomOptions::new()).unwrap(),
+            json!({})
+        );
+
+        let options = PrettyDomOptions::new()
+            .max_length(100)
+            .filter_node("return node.nodeType === Node.ELEMENT_NODE;")
+            .highlight(false);
+        assert_eq!(
+            serde_json::to_value(options).unwrap(),
+            json!({
+                "filterNode": "return node.nodeType === Node.ELEMENT_NODE;",
+                "highlight": false
+            })
+        );
+    }
+}
@@ -0,0 +1,40 @@
+/// Script performing an HTML5 drag and drop from the source to the target element.
+/// WebDriver native drag does not trigger HTML5 drag and drop handlers in Chrome so
+/// the whole sequence is dispatched in the page with a single shared DataTransfer.
+/// The drop only happens when a `dragover` listener of the target cancels the event,
+/// like in browsers. Returns whether the drop happened.
+pub(crate) const DRAG_SCRIPT: &str = r#"
+    const [source, target] = arguments;
+    const dataTransfer = new DataTransfer();
+    const center = (element) => {
+        const rect = element.getBoundingClientRect();
+        return { clientX: rect.left + rect.width / 2, clientY: rect.top + rect.height / 2 };
+    };
+    const dispatch = (element, type, position) =>
+        element.dispatchEvent(new DragEvent(type, {
+            bubbles: true,
+            cancelable: true,
+            composed: true,
+            dataTransfer,
+            ...position,
+        }));
+
+    const from = center(source);
+    const to = center(target);
+
+    if (!dispatch(source, "dragstart", from)) {
+        return false;
+    }
+    dispatch(source, "drag", from);
+    dispatch(target, "dragenter", to);
+    const accepted = !dispatch(target, "dragover", to);
+
+    if (accepted) {
+        dispatch(target, "drop", to);
+    } else {
+        dataTransfer.dropEffect = "none";
+        dispatch(target, "dragleave", to);
+    }
+    dispatch(source, "dragend", to);
+    return accepted;
+"#;
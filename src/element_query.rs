@@ -0,0 +1,224 @@
+use crate::{By, Screen};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+use thirtyfour::error::no_such_element;
+use thirtyfour::extensions::query::{
+    conditions, filter_elements, ElementPollerNoWait, ElementPollerWithTimeout, IntoElementPoller,
+};
+use thirtyfour::prelude::{WebDriverError, WebDriverResult};
+use thirtyfour::{DynElementPredicate, ElementPredicate, WebElement};
+
+/// Element query polling a Testing Library selector, with the pollers, filters and retrievers
+/// of a thirtyfour `ElementQuery`
+///
+/// Every poll runs the Testing Library query once through the screen, so frames, shadow roots
+/// and the screen configuration apply, and filters the elements it returns.
+/// The page is never modified.
+pub struct TlQuery {
+    screen: Screen,
+    selector: By,
+    poller: Arc<dyn IntoElementPoller + Send + Sync>,
+    filters: Vec<Box<DynElementPredicate>>,
+    description: Option<String>,
+    ignore_errors: bool,
+}
+
+impl Debug for TlQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlQuery")
+            .field("selector", &self.selector)
+            .field("poller", &self.poller)
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+impl TlQuery {
+    /// Query polling the selector through the screen with the default poller of the session
+    pub(crate) fn new(screen: Screen, selector: By) -> Self {
+        let poller = screen.driver.handle.config().poller.clone();
+        Self {
+            screen,
+            selector,
+            poller,
+            filters: Vec::new(),
+            description: None,
+            ignore_errors: false,
+        }
+    }
+
+    /// Name of the query in the error messages
+    pub fn desc(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Whether the state filters added afterwards ignore the errors of their WebDriver commands
+    pub fn ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+
+    /// Use the poller for this query only
+    pub fn with_poller(mut self, poller: Arc<dyn IntoElementPoller + Send + Sync>) -> Self {
+        self.poller = poller;
+        self
+    }
+
+    /// Poll until the timeout, once per interval
+    pub fn wait(self, timeout: Duration, interval: Duration) -> Self {
+        self.with_poller(Arc::new(ElementPollerWithTimeout::new(timeout, interval)))
+    }
+
+    /// Run the query once, without polling
+    pub fn nowait(self) -> Self {
+        self.with_poller(Arc::new(ElementPollerNoWait))
+    }
+
+    /// Only keep the elements matching the predicate
+    pub fn with_filter(mut self, f: impl ElementPredicate + 'static) -> Self {
+        self.filters.push(DynElementPredicate::boxed(f));
+        self
+    }
+
+    /// Only keep enabled elements
+    pub fn and_enabled(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_enabled(ignore_errors))
+    }
+
+    /// Only keep disabled elements
+    pub fn and_not_enabled(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_not_enabled(ignore_errors))
+    }
+
+    /// Only keep selected elements
+    pub fn and_selected(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_selected(ignore_errors))
+    }
+
+    /// Only keep elements that are not selected
+    pub fn and_not_selected(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_not_selected(ignore_errors))
+    }
+
+    /// Only keep displayed elements
+    pub fn and_displayed(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_displayed(ignore_errors))
+    }
+
+    /// Only keep elements that are not displayed
+    pub fn and_not_displayed(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_not_displayed(ignore_errors))
+    }
+
+    /// Only keep clickable elements
+    pub fn and_clickable(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_clickable(ignore_errors))
+    }
+
+    /// Only keep elements that are not clickable
+    pub fn and_not_clickable(self) -> Self {
+        let ignore_errors = self.ignore_errors;
+        self.with_filter(conditions::element_is_not_clickable(ignore_errors))
+    }
+
+    /// Whether an element matches once polling stops
+    pub async fn exists(&self) -> WebDriverResult<bool> {
+        Ok(!self.run_poller(false).await?.is_empty())
+    }
+
+    /// Whether no element matches once polling stops
+    pub async fn not_exists(&self) -> WebDriverResult<bool> {
+        Ok(self.run_poller(true).await?.is_empty())
+    }
+
+    /// First matching element, `None` when none matches before the timeout
+    pub async fn first_opt(&self) -> WebDriverResult<Option<WebElement>> {
+        Ok(self.run_poller(false).await?.into_iter().next())
+    }
+
+    /// First matching element, fails with `NoSuchElement` when none matches before the timeout
+    pub async fn first(&self) -> WebDriverResult<WebElement> {
+        self.first_opt().await?.ok_or_else(|| self.not_found())
+    }
+
+    /// Single matching element, fails with `NoSuchElement` when none or several match
+    pub async fn single(&self) -> WebDriverResult<WebElement> {
+        let mut elements = self.run_poller(false).await?;
+        match elements.len() {
+            0 => Err(self.not_found()),
+            1 => Ok(elements.swap_remove(0)),
+            count => Err(no_such_element(format!(
+                "too many elements received; found {count} {} using selector: {:?}",
+                self.element_description(),
+                self.selector
+            ))),
+        }
+    }
+
+    /// All matching elements once one matches, empty when none matches before the timeout
+    pub async fn any(&self) -> WebDriverResult<Vec<WebElement>> {
+        self.run_poller(false).await
+    }
+
+    /// All matching elements once one matches, fails with `NoSuchElement` when none matches
+    /// before the timeout
+    pub async fn any_required(&self) -> WebDriverResult<Vec<WebElement>> {
+        let elements = self.run_poller(false).await?;
+        if elements.is_empty() {
+            return Err(self.not_found());
+        }
+        Ok(elements)
+    }
+
+    /// Same as `any`, the query has a single selector
+    pub async fn all_from_selector(&self) -> WebDriverResult<Vec<WebElement>> {
+        self.any().await
+    }
+
+    /// Same as `any_required`, the query has a single selector
+    pub async fn all_from_selector_required(&self) -> WebDriverResult<Vec<WebElement>> {
+        self.any_required().await
+    }
+
+    /// Poll until elements match, or until none matches with `stop_on_miss`,
+    /// returning the matches of the last poll on timeout
+    async fn run_poller(&self, stop_on_miss: bool) -> WebDriverResult<Vec<WebElement>> {
+        let mut poller = self.poller.start();
+        loop {
+            let mut elements = self.screen.query_all(self.selector.clone()).await?;
+            if !elements.is_empty() {
+                elements = filter_elements(elements, &self.filters).await?;
+            }
+
+            if stop_on_miss == elements.is_empty() || !poller.tick().await {
+                return Ok(elements);
+            }
+        }
+    }
+
+    /// Elements named after the description, for the error messages
+    fn element_description(&self) -> String {
+        match &self.description {
+            Some(description) => format!("'{}' element(s)", description.escape_default()),
+            None => "element(s)".to_string(),
+        }
+    }
+
+    /// `NoSuchElement` error when no element matches
+    fn not_found(&self) -> WebDriverError {
+        no_such_element(format!(
+            "no such element: {} not found using selector: {:?}",
+            self.element_description(),
+            self.selector
+        ))
+    }
+}
@@ -0,0 +1,362 @@
+use crate::expect::AssertionFailure;
+use crate::By;
+use std::time::Duration;
+use thirtyfour::error::{no_such_element, WebDriverError, WebDriverErrorInfo};
+use thirtyfour::WebElement;
+
+/// Type alias for results returned by the testing library
+pub type TestingLibraryResult<T> = Result<T, TestingLibraryError>;
+
+/// Separator Testing Library puts between the error message and the pretty printed DOM
+const DOM_SNAPSHOT_MARKER: &str = "\n\nIgnored nodes: ";
+
+/// Name of the JavaScript errors thrown by Testing Library queries
+const ELEMENT_ERROR_NAME: &str = "TestingLibraryElementError";
+
+/// Name of the JavaScript errors thrown by custom queries matching multiple elements,
+/// whose messages are chosen by users
+const MULTIPLE_ELEMENTS_ERROR_NAME: &str = "TestingLibraryMultipleElementsError";
+
+/// Details about a failed Testing Library query
+#[derive(Debug, Clone)]
+pub struct QueryFailure {
+    /// Selector used by the query
+    pub selector: By,
+    /// Element the query was scoped to, `None` when the whole document was queried
+    pub container: Option<WebElement>,
+    /// Error message produced by Testing Library, without the DOM snapshot
+    pub message: String,
+    /// Pretty printed DOM attached to the error by Testing Library, if any
+    pub dom: Option<String>,
+}
+
+impl QueryFailure {
+    /// Create a new QueryFailure, splitting the DOM snapshot out of the raw error message
+    pub fn new(selector: By, container: Option<WebElement>, raw_message: &str) -> Self {
+        let (message, dom) = split_dom_snapshot(raw_message);
+        Self {
+            selector,
+            container,
+            message,
+            dom,
+        }
+    }
+}
+
+impl std::fmt::Display for QueryFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Errors returned by the testing library
+#[derive(Debug, thiserror::Error)]
+pub enum TestingLibraryError {
+    /// No element matched the query
+    #[error("No element found: {0}")]
+    NoElementFound(Box<QueryFailure>),
+    /// More than one element matched a query expecting a single element
+    #[error("Multiple elements found: {0}")]
+    MultipleElementsFound(Box<QueryFailure>),
+    /// A `find*` query did not match any element before its timeout
+    #[error("Find timed out: {0}")]
+    FindTimeout(Box<QueryFailure>),
+    /// Elements expected to be removed still match the selector
+    #[error("Elements still present: {0}")]
+    ElementsStillPresent(Box<QueryFailure>),
+    /// An `expect` assertion did not hold
+    #[error("{0}")]
+    AssertionFailed(Box<AssertionFailure>),
+    /// A regex used by the query is not a valid JavaScript regex
+    #[error("Invalid regex: {0}")]
+    InvalidRegex(Box<QueryFailure>),
+    /// A Rust regex uses a construct that cannot be translated to a JavaScript regex
+    #[error("Regex {pattern:?} cannot be translated to JavaScript: {reason}")]
+    UnsupportedRegex {
+        /// The Rust regex pattern
+        pattern: String,
+        /// Why the pattern cannot be translated
+        reason: String,
+    },
+    /// `wait_for` or `wait_for_element_to_be_removed` did not succeed before its timeout
+    #[error("Timed out after {}ms: {last_error}", .timeout.as_millis())]
+    WaitTimeout {
+        /// How long the wait lasted
+        timeout: Duration,
+        /// Error returned by the last check
+        last_error: Box<TestingLibraryError>,
+    },
+    /// `By::custom` uses a query that was not registered with `Screen::register_query`
+    #[error("No custom query registered as {0}")]
+    UnknownQuery(String),
+    /// `Screen::register_query` was given the name of a built-in query like `Role`
+    #[error("{0} is a built-in query and cannot be registered as a custom query")]
+    BuiltInQuery(String),
+    /// The testing library script is not available in the page and could not be injected
+    #[error("Testing Library is not injected in the page")]
+    LibraryNotInjected,
+    /// The query options or arguments could not be serialized
+    #[error("Failed to serialize script arguments: {0}")]
+    ScriptSerialization(String),
+    /// Any other JavaScript error thrown while running the query
+    #[error("{name}: {message}")]
+    Javascript {
+        /// Name of the JavaScript error
+        name: String,
+        /// Message of the JavaScript error
+        message: String,
+    },
+    /// Error returned by the WebDriver
+    #[error(transparent)]
+    WebDriver(#[from] WebDriverError),
+}
+
+impl TestingLibraryError {
+    /// Build an error from a JavaScript error thrown by a Testing Library query
+    pub(crate) fn from_query_error(
+        method_prefix: &str,
+        selector: &By,
+        container: Option<WebElement>,
+        name: &str,
+        message: &str,
+    ) -> Self {
+        let failure = || {
+            Box::new(QueryFailure::new(
+                selector.clone(),
+                container.clone(),
+                message,
+            ))
+        };
+
+        let is_find = method_prefix.starts_with("find");
+
+        if name == MULTIPLE_ELEMENTS_ERROR_NAME || message.starts_with("Found multiple elements") {
+            TestingLibraryError::MultipleElementsFound(failure())
+        } else if name == ELEMENT_ERROR_NAME && is_find {
+            TestingLibraryError::FindTimeout(failure())
+        } else if name == ELEMENT_ERROR_NAME {
+            TestingLibraryError::NoElementFound(failure())
+        } else if is_find && message.starts_with("Timed out in waitFor") {
+            TestingLibraryError::FindTimeout(failure())
+        } else if message.contains("Invalid regular expression") {
+            TestingLibraryError::InvalidRegex(failure())
+        } else {
+            TestingLibraryError::Javascript {
+                name: name.to_string(),
+                message: message.to_string(),
+            }
+        }
+    }
+
+    /// Returns the query failure details if the error comes from a failed query
+    pub fn query_failure(&self) -> Option<&QueryFailure> {
+        match self {
+            TestingLibraryError::NoElementFound(failure)
+            | TestingLibraryError::MultipleElementsFound(failure)
+            | TestingLibraryError::FindTimeout(failure)
+            | TestingLibraryError::ElementsStillPresent(failure)
+            | TestingLibraryError::InvalidRegex(failure) => Some(failure),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for TestingLibraryError {
+    fn from(error: serde_json::Error) -> Self {
+        TestingLibraryError::ScriptSerialization(error.to_string())
+    }
+}
+
+impl From<TestingLibraryError> for WebDriverError {
+    fn from(error: TestingLibraryError) -> Self {
+        let message = error.to_string();
+        match error {
+            TestingLibraryError::NoElementFound(_)
+            | TestingLibraryError::MultipleElementsFound(_) => no_such_element(message),
+            TestingLibraryError::FindTimeout(_)
+            | TestingLibraryError::ElementsStillPresent(_)
+            | TestingLibraryError::WaitTimeout { .. } => WebDriverError::Timeout(message),
+            TestingLibraryError::AssertionFailed(_) => {
+                WebDriverError::InvalidElementState(WebDriverErrorInfo::new(message))
+            }
+            TestingLibraryError::InvalidRegex(_)
+            | TestingLibraryError::UnsupportedRegex { .. }
+            | TestingLibraryError::UnknownQuery(_) => {
+                WebDriverError::InvalidSelector(WebDriverErrorInfo::new(message))
+            }
+            TestingLibraryError::BuiltInQuery(_) => {
+                WebDriverError::InvalidArgument(WebDriverErrorInfo::new(message))
+            }
+            TestingLibraryError::LibraryNotInjected | TestingLibraryError::Javascript { .. } => {
+                WebDriverError::JavascriptError(WebDriverErrorInfo::new(message))
+            }
+            TestingLibraryError::ScriptSerialization(_) => WebDriverError::Json(message),
+            TestingLibraryError::WebDriver(error) => error,
+        }
+    }
+}
+
+/// Split a Testing Library error message into the message itself and the DOM snapshot
+fn split_dom_snapshot(raw_message: &str) -> (String, Option<String>) {
+    let Some(start) = raw_message.find(DOM_SNAPSHOT_MARKER) else {
+        return (raw_message.to_string(), None);
+    };
+
+    // find* errors are wrapped twice so the snapshot may be repeated, keep the last one
+    let last = raw_message.rfind(DOM_SNAPSHOT_MARKER).unwrap_or(start);
+    let snapshot = &raw_message[last + DOM_SNAPSHOT_MARKER.len()..];
+    let dom = snapshot
+        .split_once('\n')
+        .map(|(_, dom)| dom)
+        .unwrap_or_default();
+
+    (raw_message[..start].to_string(), Some(dom.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thirtyfour::error::WebDriverErrorInner;
+
+    const NOT_FOUND: &str = "Unable to find an element with the text: Missing. This could be because the text is broken up by multiple elements.\n\nIgnored nodes: comments, script, style\n<body>\n  <div>\n    Login\n  </div>\n</body>";
+
+    #[test]
+    fn test_split_dom_snapshot() {
+        let (message, dom) = split_dom_snapshot(NOT_FOUND);
+        assert!(message.starts_with("Unable to find an element with the text: Missing."));
+        assert!(!message.contains("<body>"));
+        assert_eq!(
+            dom.as_deref(),
+            Some("<body>\n  <div>\n    Login\n  </div>\n</body>")
+        );
+
+        let (message, dom) = split_dom_snapshot("Timed out in waitFor.");
+        assert_eq!(message, "Timed out in waitFor.");
+        assert!(dom.is_none());
+    }
+
+    #[test]
+    fn test_split_dom_snapshot_repeated() {
+        let repeated = format!("{NOT_FOUND}{DOM_SNAPSHOT_MARKER}comments, script, style\n<body />");
+        let (message, dom) = split_dom_snapshot(&repeated);
+        assert!(message.starts_with("Unable to find"));
+        assert_eq!(dom.as_deref(), Some("<body />"));
+    }
+
+    #[test]
+    fn test_into_webdriver_error() {
+        let into_inner = |error: TestingLibraryError| WebDriverError::from(error).into_inner();
+
+        assert!(matches!(
+            into_inner(TestingLibraryError::Javascript {
+                name: "TypeError".to_string(),
+                message: "x is not a function".to_string(),
+            }),
+            WebDriverErrorInner::JavascriptError(_)
+        ));
+        assert!(matches!(
+            into_inner(TestingLibraryError::LibraryNotInjected),
+            WebDriverErrorInner::JavascriptError(_)
+        ));
+        assert!(matches!(
+            into_inner(TestingLibraryError::UnknownQuery("DataCy".to_string())),
+            WebDriverErrorInner::InvalidSelector(_)
+        ));
+        assert!(matches!(
+            into_inner(TestingLibraryError::UnsupportedRegex {
+                pattern: "(?<=a)b".to_string(),
+                reason: "lookbehind".to_string(),
+            }),
+            WebDriverErrorInner::InvalidSelector(_)
+        ));
+
+        assert!(matches!(
+            into_inner(TestingLibraryError::BuiltInQuery("Role".to_string())),
+            WebDriverErrorInner::InvalidArgument(_)
+        ));
+
+        let error = into_inner(TestingLibraryError::UnknownQuery("DataCy".to_string()));
+        assert!(error
+            .to_string()
+            .contains("No custom query registered as DataCy"));
+    }
+
+    #[test]
+    fn test_from_query_error() {
+        let selector: By = By::text("Missing").into();
+
+        let error = TestingLibraryError::from_query_error(
+            "getBy",
+            &selector,
+            None,
+            ELEMENT_ERROR_NAME,
+            NOT_FOUND,
+        );
+        assert!(matches!(error, TestingLibraryError::NoElementFound(_)));
+        assert!(error.query_failure().unwrap().dom.is_some());
+
+        let error = TestingLibraryError::from_query_error(
+            "findBy",
+            &selector,
+            None,
+            ELEMENT_ERROR_NAME,
+            NOT_FOUND,
+        );
+        assert!(matches!(error, TestingLibraryError::FindTimeout(_)));
+
+        let error = TestingLibraryError::from_query_error(
+            "getBy",
+            &selector,
+            None,
+            ELEMENT_ERROR_NAME,
+            "Found multiple elements with the text: Missing",
+        );
+        assert!(matches!(
+            error,
+            TestingLibraryError::MultipleElementsFound(_)
+        ));
+
+        let error = TestingLibraryError::from_query_error(
+            "findBy",
+            &selector,
+            None,
+            MULTIPLE_ELEMENTS_ERROR_NAME,
+            "Too many cards",
+        );
+        assert!(matches!(
+            error,
+            TestingLibraryError::MultipleElementsFound(_)
+        ));
+
+        let error = TestingLibraryError::from_query_error(
+            "getBy",
+            &selector,
+            None,
+            "SyntaxError",
+            "Invalid regular expression: /[/: Unterminated character class",
+        );
+        assert!(matches!(error, TestingLibraryError::InvalidRegex(_)));
+
+        let error = TestingLibraryError::from_query_error(
+            "getBy",
+            &selector,
+            None,
+            "SyntaxError",
+            "Unexpected token '}'",
+        );
+        assert!(matches!(
+            error,
+            TestingLibraryError::Javascript { ref name, .. } if name == "SyntaxError"
+        ));
+
+        let error = TestingLibraryError::from_query_error(
+            "getBy",
+            &selector,
+            None,
+            "TypeError",
+            "x is not a function",
+        );
+        assert!(matches!(error, TestingLibraryError::Javascript { .. }));
+        assert!(error.query_failure().is_none());
+    }
+}
@@ -0,0 +1,396 @@
+use crate::error::{TestingLibraryError, TestingLibraryResult};
+use crate::options::common::REVIVE_SCRIPT;
+use crate::wait::{self, WaitForOptions};
+use crate::{Screen, TextMatch};
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
+use thirtyfour::error::WebDriverErrorInner;
+use thirtyfour::WebElement;
+
+/// Details about a failed assertion
+#[derive(Debug, Clone)]
+pub struct AssertionFailure {
+    /// Assertion that failed, like `expect(element).toBeVisible()`
+    pub assertion: String,
+    /// Why the assertion failed
+    pub message: String,
+    /// Pretty printed element, `None` when the element is not in the page anymore
+    pub element: Option<String>,
+}
+
+impl fmt::Display for AssertionFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\n\n{}", self.assertion, self.message)?;
+        if let Some(element) = &self.element {
+            write!(f, "\n\n{element}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Start an assertion on an element, like jest-dom `expect(element)`
+///
+/// Elements found in a frame must be asserted with `Screen::expect` on the frame screen.
+pub fn expect(element: &WebElement) -> Expect {
+    Expect::new(Screen::from_handle(element.handle.clone()), element)
+}
+
+/// Assertions on an element, each one is evaluated in the browser in a single round trip
+#[derive(Debug, Clone)]
+pub struct Expect {
+    screen: Screen,
+    element: WebElement,
+    negated: bool,
+    eventually: Option<WaitForOptions>,
+    default_timeout: Duration,
+}
+
+impl Expect {
+    /// Script evaluating the named matcher on an element with the matcher arguments, negated or not.
+    /// Returns `{pass, message, element}` with the element formatted by `prettyDOM`
+    /// up to the given max length.
+    const EXPECT_SCRIPT: &str = r#"
+        const [matcher, element, args, negated, maxLength] = arguments;
+
+        const quote = (value) => JSON.stringify(value);
+        const normalize = (text) => text.replace(/\s+/g, " ").trim();
+        const matches = (text, expected) => {
+            if (typeof expected === "string") return text.includes(expected);
+            if (expected instanceof RegExp) {
+                expected.lastIndex = 0;
+                return expected.test(text);
+            }
+            return expected(text, element);
+        };
+        const matchesExactly = (text, expected) => (typeof expected === "string" ? text === expected : matches(text, expected));
+        const isStyleVisible = (node) => {
+            const { display, visibility, opacity } = node.ownerDocument.defaultView.getComputedStyle(node);
+            return display !== "none" && visibility !== "hidden" && visibility !== "collapse" && opacity !== "0";
+        };
+        const isVisible = (node, previous) =>
+            isStyleVisible(node) &&
+            !node.hasAttribute("hidden") &&
+            (node.nodeName !== "DETAILS" || previous?.nodeName === "SUMMARY" || node.hasAttribute("open")) &&
+            (!node.parentElement || isVisible(node.parentElement, node));
+        const value = (node) => {
+            if (node.tagName === "SELECT" && node.multiple) {
+                return Array.from(node.selectedOptions).map((option) => option.value);
+            }
+            if (node.tagName === "INPUT" && ["checkbox", "radio"].includes(node.type)) {
+                throw new TypeError("input with type=checkbox or type=radio cannot be used with toHaveValue, use toBeChecked instead");
+            }
+            if (node.tagName === "INPUT" && node.type === "number") {
+                return node.value === "" ? null : Number(node.value);
+            }
+            return "value" in node ? node.value : node.getAttribute("aria-valuenow");
+        };
+        const checkableRoles = ["checkbox", "radio", "switch", "menuitemcheckbox", "menuitemradio", "option", "treeitem"];
+
+        const matchers = {
+            toBeInTheDocument: () => ({
+                pass: element.isConnected,
+                message: (not) => `Expected element ${not ? "not " : ""}to be in the document`,
+            }),
+            toBeVisible: () => ({
+                pass: element.isConnected && isVisible(element),
+                message: (not) => `Received element is ${not ? "" : "not "}visible`,
+            }),
+            toBeDisabled: () => ({
+                pass: element.matches(":disabled"),
+                message: (not) => `Received element is ${not ? "" : "not "}disabled`,
+            }),
+            toBeChecked: () => {
+                const role = element.getAttribute("role");
+                let checked;
+                if (element.tagName === "INPUT" && ["checkbox", "radio"].includes(element.type)) {
+                    checked = element.checked;
+                } else if (checkableRoles.includes(role)) {
+                    checked = element.getAttribute("aria-checked") === "true";
+                } else {
+                    throw new TypeError("only inputs with type=checkbox or type=radio or elements with a checkable role can be used with toBeChecked");
+                }
+                return {
+                    pass: checked,
+                    message: (not) => `Received element is ${not ? "" : "not "}checked`,
+                };
+            },
+            toHaveValue: ([expected]) => {
+                const received = value(element);
+                return {
+                    pass: JSON.stringify(received) === JSON.stringify(expected),
+                    message: (not) => `Expected the element ${not ? "not " : ""}to have value:\n  ${quote(expected)}\nReceived:\n  ${quote(received)}`,
+                };
+            },
+            toHaveTextContent: ([expected]) => {
+                const received = normalize(element.textContent);
+                return {
+                    pass: matches(received, expected),
+                    message: (not) => `Expected element ${not ? "not " : ""}to have text content:\n  ${typeof expected === "string" ? quote(expected) : expected}\nReceived:\n  ${quote(received)}`,
+                };
+            },
+            toHaveAttribute: ([name, expected]) => {
+                const received = element.getAttribute(name);
+                const pass = element.hasAttribute(name) && (expected === null || received === expected);
+                const expectation = expected === null ? name : `${name}=${quote(expected)}`;
+                return {
+                    pass,
+                    message: (not) => `Expected the element ${not ? "not " : ""}to have attribute:\n  ${expectation}\nReceived:\n  ${received === null ? "null" : `${name}=${quote(received)}`}`,
+                };
+            },
+            toHaveClass: ([classNames]) => {
+                const expected = classNames.split(/\s+/).filter(Boolean);
+                const received = Array.from(element.classList);
+                return {
+                    pass: expected.length > 0 && expected.every((className) => received.includes(className)),
+                    message: (not) => `Expected the element ${not ? "not " : ""}to have class:\n  ${expected.join(" ")}\nReceived:\n  ${received.join(" ")}`,
+                };
+            },
+            toHaveAccessibleName: ([expected]) => {
+                const received = window.__TL__.computeAccessibleName(element);
+                return {
+                    pass: matchesExactly(received, expected),
+                    message: (not) => `Expected element ${not ? "not " : ""}to have accessible name:\n  ${typeof expected === "string" ? quote(expected) : expected}\nReceived:\n  ${quote(received)}`,
+                };
+            },
+            toHaveAccessibleDescription: ([expected]) => {
+                const received = window.__TL__.computeAccessibleDescription(element);
+                return {
+                    pass: matchesExactly(received, expected),
+                    message: (not) => `Expected element ${not ? "not " : ""}to have accessible description:\n  ${typeof expected === "string" ? quote(expected) : expected}\nReceived:\n  ${quote(received)}`,
+                };
+            },
+            toHaveFocus: () => ({
+                pass: element.ownerDocument.activeElement === element,
+                message: (not) => `Expected element ${not ? "not " : ""}to have focus`,
+            }),
+        };
+
+        const { pass, message } = matchers[matcher](revive(args));
+        return { pass: pass !== negated, message: message(negated), element: window.__TL__.prettyDOM(element, maxLength) };
+    "#;
+
+    /// Start an assertion run by the screen, in its frames and with its configure options
+    pub(crate) fn new(screen: Screen, element: &WebElement) -> Self {
+        let default_timeout = wait::async_util_timeout(screen.configure_options.as_ref());
+        Self {
+            screen,
+            element: element.clone(),
+            negated: false,
+            eventually: None,
+            default_timeout,
+        }
+    }
+
+    /// Negate the assertion, like jest-dom `expect(element).not`
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    /// Retry the assertion until it passes, reporting the last failure on timeout
+    pub fn eventually(self) -> Self {
+        self.eventually_with(WaitForOptions::default())
+    }
+
+    /// Retry the assertion until it passes, with custom timeout, interval and on_timeout
+    pub fn eventually_with(mut self, options: WaitForOptions) -> Self {
+        self.eventually = Some(options);
+        self
+    }
+
+    /// Assert that the element is still attached to the document
+    pub async fn to_be_in_the_document(&self) -> TestingLibraryResult<()> {
+        self.assert("toBeInTheDocument", vec![]).await
+    }
+
+    /// Assert that the element and its ancestors are displayed, not hidden and fully opaque
+    pub async fn to_be_visible(&self) -> TestingLibraryResult<()> {
+        self.assert("toBeVisible", vec![]).await
+    }
+
+    /// Assert that the element is a disabled form element or is in a disabled fieldset
+    pub async fn to_be_disabled(&self) -> TestingLibraryResult<()> {
+        self.assert("toBeDisabled", vec![]).await
+    }
+
+    /// Assert that the checkbox, radio or element with a checkable role is checked
+    pub async fn to_be_checked(&self) -> TestingLibraryResult<()> {
+        self.assert("toBeChecked", vec![]).await
+    }
+
+    /// Assert the value of a form element, numbers for number inputs and arrays for multiple selects
+    pub async fn to_have_value(&self, value: impl Into<Value>) -> TestingLibraryResult<()> {
+        self.assert("toHaveValue", vec![value.into()]).await
+    }
+
+    /// Assert that the whitespace normalized text content contains the string or matches the regex
+    pub async fn to_have_text_content(
+        &self,
+        text: impl Into<TextMatch>,
+    ) -> TestingLibraryResult<()> {
+        let text = serde_json::to_value(text.into())?;
+        self.assert("toHaveTextContent", vec![text]).await
+    }
+
+    /// Assert that the element has the attribute, with the given value if any
+    pub async fn to_have_attribute(
+        &self,
+        name: &str,
+        value: Option<&str>,
+    ) -> TestingLibraryResult<()> {
+        self.assert(
+            "toHaveAttribute",
+            vec![Value::from(name), Value::from(value)],
+        )
+        .await
+    }
+
+    /// Assert that the element has all the space separated classes
+    pub async fn to_have_class(&self, class_names: &str) -> TestingLibraryResult<()> {
+        self.assert("toHaveClass", vec![Value::from(class_names)])
+            .await
+    }
+
+    /// Assert the accessible name of the element, strings must match exactly,
+    /// the Testing Library script must be loaded in the page
+    pub async fn to_have_accessible_name(
+        &self,
+        name: impl Into<TextMatch>,
+    ) -> TestingLibraryResult<()> {
+        let name = serde_json::to_value(name.into())?;
+        self.assert("toHaveAccessibleName", vec![name]).await
+    }
+
+    /// Assert the accessible description of the element, strings must match exactly,
+    /// the Testing Library script must be loaded in the page
+    pub async fn to_have_accessible_description(
+        &self,
+        description: impl Into<TextMatch>,
+    ) -> TestingLibraryResult<()> {
+        let description = serde_json::to_value(description.into())?;
+        self.assert("toHaveAccessibleDescription", vec![description])
+            .await
+    }
+
+    /// Assert that the element is the focused element of its document
+    pub async fn to_have_focus(&self) -> TestingLibraryResult<()> {
+        self.assert("toHaveFocus", vec![]).await
+    }
+
+    /// Evaluate the matcher in the browser, until it passes with `eventually`
+    async fn assert(&self, matcher: &str, arguments: Vec<Value>) -> TestingLibraryResult<()> {
+        match &self.eventually {
+            Some(options) => {
+                wait::wait_until(options.clone(), self.default_timeout, || {
+                    self.assert_once(matcher, arguments.clone())
+                })
+                .await
+            }
+            None => self.assert_once(matcher, arguments).await,
+        }
+    }
+
+    /// Evaluate the matcher in the browser once
+    async fn assert_once(&self, matcher: &str, arguments: Vec<Value>) -> TestingLibraryResult<()> {
+        let assertion = format!(
+            "expect(element).{}{matcher}()",
+            if self.negated { "not." } else { "" }
+        );
+        let script = format!("{REVIVE_SCRIPT}{}", Self::EXPECT_SCRIPT);
+        let script_arguments = vec![
+            Value::from(matcher),
+            self.element.to_json()?,
+            Value::Array(arguments),
+            Value::Bool(self.negated),
+            Value::from(self.screen.debug_print_limit()),
+        ];
+
+        let result = match self
+            .screen
+            .query_executor()
+            .execute_catching(&script, script_arguments)
+            .await
+        {
+            Ok(result) => result.json().clone(),
+            // Stale elements are not in the document anymore
+            Err(TestingLibraryError::WebDriver(error))
+                if matches!(
+                    error.as_inner(),
+                    WebDriverErrorInner::StaleElementReference(_)
+                ) =>
+            {
+                return self.stale_element(matcher, assertion, error.into());
+            }
+            Err(error) => return Err(error),
+        };
+
+        if result["pass"].as_bool().unwrap_or_default() {
+            return Ok(());
+        }
+
+        Err(TestingLibraryError::AssertionFailed(Box::new(
+            AssertionFailure {
+                assertion,
+                message: result["message"].as_str().unwrap_or_default().to_string(),
+                element: result["element"].as_str().map(str::to_string),
+            },
+        )))
+    }
+
+    /// Outcome of an assertion on an element removed from the page
+    fn stale_element(
+        &self,
+        matcher: &str,
+        assertion: String,
+        error: TestingLibraryError,
+    ) -> TestingLibraryResult<()> {
+        match (matcher, self.negated) {
+            ("toBeInTheDocument" | "toBeVisible", true) => Ok(()),
+            ("toBeInTheDocument", false) => Err(TestingLibraryError::AssertionFailed(Box::new(
+                AssertionFailure {
+                    assertion,
+                    message: "Expected element to be in the document".to_string(),
+                    element: None,
+                },
+            ))),
+            ("toBeVisible", false) => Err(TestingLibraryError::AssertionFailed(Box::new(
+                AssertionFailure {
+                    assertion,
+                    message: "Received element is not visible (removed from the document)"
+                        .to_string(),
+                    element: None,
+                },
+            ))),
+            _ => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assertion_failure_display() {
+        let failure = AssertionFailure {
+            assertion: "expect(element).toBeVisible()".to_string(),
+            message: "Received element is not visible".to_string(),
+            element: Some("<div\n  hidden=\"\"\n/>".to_string()),
+        };
+        assert_eq!(
+            failure.to_string(),
+            "expect(element).toBeVisible()\n\nReceived element is not visible\n\n<div\n  hidden=\"\"\n/>"
+        );
+
+        let failure = AssertionFailure {
+            element: None,
+            ..failure
+        };
+        assert_eq!(
+            failure.to_string(),
+            "expect(element).toBeVisible()\n\nReceived element is not visible"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{By, Screen, TestingLibraryResult, TlQuery};
+use std::future::Future;
+use thirtyfour::{WebDriver, WebElement};
+
+/// Testing Library queries scoped to an element, like `Screen::within(element)`
+///
+/// The element does not know the `Screen` it was found with, so these queries use the default
+/// configure options and no custom queries. Use `screen.within(element)` to keep the
+/// configuration of `screen`.
+pub trait TestingLibraryElementExt {
+    /// Screen scoped to this element, with the default configure options and no custom queries
+    fn screen(&self) -> Screen;
+
+    /// Get the single element matching the selector inside this element, see `Screen::get`
+    fn get_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<WebElement>> + Send;
+
+    /// Get all the elements matching the selector inside this element, see `Screen::get_all`
+    fn get_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send;
+
+    /// Query the element matching the selector inside this element, see `Screen::query`
+    fn query_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Option<WebElement>>> + Send;
+
+    /// Query all the elements matching the selector inside this element, see `Screen::query_all`
+    fn query_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send;
+
+    /// Wait for the element matching the selector inside this element, see `Screen::find`
+    fn find_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<WebElement>> + Send;
+
+    /// Wait for the elements matching the selector inside this element, see `Screen::find_all`
+    fn find_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send;
+}
+
+impl TestingLibraryElementExt for WebElement {
+    fn screen(&self) -> Screen {
+        Screen::from_handle(self.handle.clone()).within(self.clone())
+    }
+
+    fn get_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<WebElement>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.get(selector).await }
+    }
+
+    fn get_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.get_all(selector).await }
+    }
+
+    fn query_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Option<WebElement>>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.query(selector).await }
+    }
+
+    fn query_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.query_all(selector).await }
+    }
+
+    fn find_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<WebElement>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.find(selector).await }
+    }
+
+    fn find_all_by(
+        &self,
+        selector: impl Into<By>,
+    ) -> impl Future<Output = TestingLibraryResult<Vec<WebElement>>> + Send {
+        let (screen, selector) = (self.screen(), selector.into());
+        async move { screen.find_all(selector).await }
+    }
+}
+
+/// Build a `Screen` straight from the driver
+pub trait TestingLibraryDriverExt {
+    /// Screen on the whole page, with the Testing Library script loaded,
+    /// see `Screen::build_with_testing_library`
+    fn screen(&self) -> impl Future<Output = TestingLibraryResult<Screen>> + Send;
+}
+
+impl TestingLibraryDriverExt for WebDriver {
+    fn screen(&self) -> impl Future<Output = TestingLibraryResult<Screen>> + Send {
+        Screen::build_with_testing_library(self.clone())
+    }
+}
+
+/// Thirtyfour-style element queries driven by Testing Library selectors, to use thirtyfour
+/// polling and filters (`and_displayed`, `first`, `nowait`, ...) with accessible queries
+pub trait TestingLibraryQueryable {
+    /// Start an element query on the elements matching the selector,
+    /// the Testing Library query runs once per poll
+    fn tl_query(&self, selector: impl Into<By>) -> TlQuery;
+}
+
+impl TestingLibraryQueryable for WebDriver {
+    fn tl_query(&self, selector: impl Into<By>) -> TlQuery {
+        TlQuery::new(Screen::from_handle(self.handle.clone()), selector.into())
+    }
+}
+
+/// Queries inside the element with the default configuration, see `TestingLibraryElementExt`
+impl TestingLibraryQueryable for WebElement {
+    fn tl_query(&self, selector: impl Into<By>) -> TlQuery {
+        TlQuery::new(self.screen(), selector.into())
+    }
+}
+
+/// Queries through the screen, keeping its scope, frames and configuration
+impl TestingLibraryQueryable for Screen {
+    fn tl_query(&self, selector: impl Into<By>) -> TlQuery {
+        TlQuery::new(self.clone(), selector.into())
+    }
+}
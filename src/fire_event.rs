@@ -0,0 +1,282 @@
+use crate::error::TestingLibraryResult;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// Keyboard event properties
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyboardEventInit {
+    /// Value of the key, like `Enter` or `a`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Physical key, like `Enter` or `KeyA`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Whether the Alt key is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_key: Option<bool>,
+    /// Whether the Control key is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctrl_key: Option<bool>,
+    /// Whether the Meta key is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta_key: Option<bool>,
+    /// Whether the Shift key is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_key: Option<bool>,
+}
+
+impl KeyboardEventInit {
+    /// Create a new instance for the given key
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: Some(key.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the code option
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Set the alt_key option
+    pub fn alt_key(mut self, alt_key: bool) -> Self {
+        self.alt_key = Some(alt_key);
+        self
+    }
+
+    /// Set the ctrl_key option
+    pub fn ctrl_key(mut self, ctrl_key: bool) -> Self {
+        self.ctrl_key = Some(ctrl_key);
+        self
+    }
+
+    /// Set the meta_key option
+    pub fn meta_key(mut self, meta_key: bool) -> Self {
+        self.meta_key = Some(meta_key);
+        self
+    }
+
+    /// Set the shift_key option
+    pub fn shift_key(mut self, shift_key: bool) -> Self {
+        self.shift_key = Some(shift_key);
+        self
+    }
+}
+
+/// Data carried by the `DataTransfer` of drag events
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DataTransfer(Map<String, Value>);
+
+impl DataTransfer {
+    /// Create an empty DataTransfer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add data for a format, like `text/plain`
+    pub fn data(mut self, format: impl Into<String>, data: impl Into<String>) -> Self {
+        self.0.insert(format.into(), Value::String(data.into()));
+        self
+    }
+}
+
+/// DOM events fired with Testing Library `fireEvent`
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// `click` mouse event
+    Click,
+    /// `dblclick` mouse event
+    DblClick,
+    /// `mousedown` mouse event
+    MouseDown,
+    /// `mouseup` mouse event
+    MouseUp,
+    /// `mouseover` mouse event
+    MouseOver,
+    /// `mouseout` mouse event
+    MouseOut,
+    /// `focus` event
+    Focus,
+    /// `blur` event
+    Blur,
+    /// `keydown` keyboard event
+    KeyDown(KeyboardEventInit),
+    /// `keyup` keyboard event
+    KeyUp(KeyboardEventInit),
+    /// `input` event, the value is assigned to the element first
+    Input(String),
+    /// `change` event, the value is assigned to the element first
+    Change(String),
+    /// `submit` event
+    Submit,
+    /// `scroll` event, the vertical scroll position is assigned to the element first
+    Scroll {
+        /// Vertical scroll position
+        top: f64,
+    },
+    /// `transitionend` event
+    TransitionEnd {
+        /// CSS property the transition was running on
+        property_name: String,
+    },
+    /// `animationend` event
+    AnimationEnd {
+        /// Name of the CSS animation
+        animation_name: String,
+    },
+    /// `dragstart` drag event
+    DragStart(DataTransfer),
+    /// `dragenter` drag event
+    DragEnter(DataTransfer),
+    /// `dragover` drag event
+    DragOver(DataTransfer),
+    /// `dragleave` drag event
+    DragLeave(DataTransfer),
+    /// `drop` drag event
+    Drop(DataTransfer),
+    /// `dragend` drag event
+    DragEnd(DataTransfer),
+    /// Any other event, by `fireEvent` name (like `pointerDown`) or DOM event type
+    Custom {
+        /// `fireEvent` name or DOM event type, unknown types are fired as a `CustomEvent`
+        name: String,
+        /// Event init dictionary
+        init: Value,
+    },
+}
+
+impl Event {
+    /// Create a custom event with a serialized init dictionary
+    pub fn custom(name: impl Into<String>, init: impl Serialize) -> TestingLibraryResult<Self> {
+        Ok(Event::Custom {
+            name: name.into(),
+            init: serde_json::to_value(init)?,
+        })
+    }
+
+    /// Name of the event in Testing Library `createEvent`
+    pub fn name(&self) -> &str {
+        match self {
+            Event::Click => "click",
+            Event::DblClick => "dblClick",
+            Event::MouseDown => "mouseDown",
+            Event::MouseUp => "mouseUp",
+            Event::MouseOver => "mouseOver",
+            Event::MouseOut => "mouseOut",
+            Event::Focus => "focus",
+            Event::Blur => "blur",
+            Event::KeyDown(_) => "keyDown",
+            Event::KeyUp(_) => "keyUp",
+            Event::Input(_) => "input",
+            Event::Change(_) => "change",
+            Event::Submit => "submit",
+            Event::Scroll { .. } => "scroll",
+            Event::TransitionEnd { .. } => "transitionEnd",
+            Event::AnimationEnd { .. } => "animationEnd",
+            Event::DragStart(_) => "dragStart",
+            Event::DragEnter(_) => "dragEnter",
+            Event::DragOver(_) => "dragOver",
+            Event::DragLeave(_) => "dragLeave",
+            Event::Drop(_) => "drop",
+            Event::DragEnd(_) => "dragEnd",
+            Event::Custom { name, .. } => name,
+        }
+    }
+
+    /// Event init dictionary given to `createEvent`
+    pub(crate) fn init(&self) -> TestingLibraryResult<Value> {
+        Ok(match self {
+            Event::KeyDown(init) | Event::KeyUp(init) => serde_json::to_value(init)?,
+            Event::Input(value) | Event::Change(value) => json!({ "target": { "value": value } }),
+            Event::Scroll { top } => json!({ "target": { "scrollTop": top } }),
+            Event::TransitionEnd { property_name } => json!({ "propertyName": property_name }),
+            Event::AnimationEnd { animation_name } => json!({ "animationName": animation_name }),
+            Event::Custom { init, .. } => init.clone(),
+            _ => json!({}),
+        })
+    }
+
+    /// Data to put in the event DataTransfer, `null` for events without one
+    pub(crate) fn data_transfer(&self) -> TestingLibraryResult<Value> {
+        match self {
+            Event::DragStart(data)
+            | Event::DragEnter(data)
+            | Event::DragOver(data)
+            | Event::DragLeave(data)
+            | Event::Drop(data)
+            | Event::DragEnd(data) => Ok(serde_json::to_value(data)?),
+            _ => Ok(Value::Null),
+        }
+    }
+}
+
+/// Script firing the named event on an element with the init dictionary and the DataTransfer data.
+/// Returns `false` when a listener cancelled the event.
+pub(crate) const FIRE_EVENT_SCRIPT: &str = r#"
+    const [name, element, init, transferData] = arguments;
+    const { createEvent, fireEvent } = window.__TL__;
+    const event = createEvent[name]
+        ? createEvent[name](element, init)
+        : createEvent(name, element, init, {
+            EventType: "CustomEvent",
+            defaultInit: { bubbles: true, cancelable: true, composed: true },
+        });
+    if (transferData) {
+        const dataTransfer = new DataTransfer();
+        Object.entries(transferData).forEach(([format, data]) => dataTransfer.setData(format, data));
+        Object.defineProperty(event, "dataTransfer", { value: dataTransfer });
+    }
+    return fireEvent(element, event);
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_init() {
+        assert_eq!(Event::Click.init().unwrap(), json!({}));
+        assert_eq!(
+            Event::Change("apple".to_string()).init().unwrap(),
+            json!({"target": {"value": "apple"}})
+        );
+        assert_eq!(
+            Event::KeyDown(KeyboardEventInit::new("Enter").shift_key(true))
+                .init()
+                .unwrap(),
+            json!({"key": "Enter", "shiftKey": true})
+        );
+        assert_eq!(
+            Event::TransitionEnd {
+                property_name: "opacity".to_string()
+            }
+            .name(),
+            "transitionEnd"
+        );
+    }
+
+    #[test]
+    fn test_custom_event() {
+        #[derive(Serialize)]
+        struct Detail {
+            id: u32,
+        }
+
+        let event = Event::custom("card-selected", json!({ "detail": Detail { id: 42 } })).unwrap();
+        assert_eq!(event.name(), "card-selected");
+        assert_eq!(event.init().unwrap(), json!({"detail": {"id": 42}}));
+        assert!(event.data_transfer().unwrap().is_null());
+    }
+
+    #[test]
+    fn test_data_transfer() {
+        let event = Event::DragOver(DataTransfer::new().data("text/plain", "card-1"));
+        assert_eq!(
+            event.data_transfer().unwrap(),
+            json!({"text/plain": "card-1"})
+        );
+    }
+}
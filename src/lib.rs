@@ -54,23 +54,63 @@
 //!
 //! Each selector type supports options for advanced filtering and matching.
 
+/// Accessible name and description computed in the page
+mod accessibility;
+/// Page object components resolved with Testing Library queries
+pub mod component;
 /// Configuration options for the testing library
 pub mod configure;
+/// Custom queries registered in the page
+pub mod custom_query;
+/// Pretty printed DOM for debugging
+pub mod debug;
+/// HTML5 drag and drop dispatched in the page
+mod drag;
+/// Thirtyfour-style element queries polling Testing Library selectors
+pub mod element_query;
+/// Errors returned by the testing library
+pub mod error;
+/// jest-dom style assertions on elements
+pub mod expect;
+/// Extension traits querying from elements and drivers
+pub mod ext;
+/// Low-level DOM events backed by fireEvent
+pub mod fire_event;
 /// Testing library options module
 pub mod options;
-
+/// Roles listing for debugging role queries
+pub mod roles;
+/// Accessible query suggestions for elements
+pub mod suggest;
+/// Realistic user interactions backed by user-event
+pub mod user_event;
+/// Waiting utilities driven from Rust
+pub mod wait;
+
+pub use custom_query::QueryErrorMessages;
+pub use debug::PrettyDomOptions;
+pub use element_query::TlQuery;
+pub use error::{QueryFailure, TestingLibraryError, TestingLibraryResult};
+pub use expect::{expect, AssertionFailure, Expect};
+pub use ext::{TestingLibraryDriverExt, TestingLibraryElementExt, TestingLibraryQueryable};
+pub use fire_event::{DataTransfer, Event, KeyboardEventInit};
+pub use thirtyfour_testing_library_ext_macros::TestingLibraryComponent;
+pub use user_event::{Target, UserEvent, UserEventOptions};
+pub use wait::{Eventually, WaitForOptions};
 // Re-export all options for convenience
+use custom_query::{CustomQuery, INSTALL_QUERY_SCRIPT};
+use options::common::REVIVE_SCRIPT;
 pub use options::*;
+pub use roles::RolesOptions;
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::Duration;
+pub use suggest::QueryMethod;
 
-use thirtyfour::{
-    error::{WebDriverError, WebDriverResult},
-    prelude::ScriptRet,
-    WebDriver, WebElement,
-};
-
-// TODO
-// - better error handling
+use std::sync::Arc;
+use thirtyfour::session::handle::SessionHandle;
+use thirtyfour::{prelude::ScriptRet, WebDriver, WebElement};
 
 /// A struct representing a screen in the testing library that provides DOM queries with different behaviors: get* methods throw errors if elements aren't found, query* methods return null for missing elements, and find* methods return promises that retry until elements are found.
 #[derive(Debug, Clone)]
@@ -78,17 +118,25 @@ pub struct Screen {
     driver: WebDriver,
     within_element: Option<WebElement>,
     configure_options: Option<configure::Options>,
+    frames: Vec<WebElement>,
+    within_shadow_root: bool,
+    pierce_shadow: bool,
+    custom_queries: Vec<CustomQuery>,
 }
 
 impl Screen {
     /// Creates a new `Screen` and loads the testing library script in the browser
-    pub async fn build_with_testing_library(driver: WebDriver) -> WebDriverResult<Self> {
+    pub async fn build_with_testing_library(driver: WebDriver) -> TestingLibraryResult<Self> {
         Self::load_testing_library(&driver).await?;
 
         Ok(Screen {
             driver,
             within_element: None,
             configure_options: None,
+            frames: Vec::new(),
+            within_shadow_root: false,
+            pierce_shadow: false,
+            custom_queries: Vec::new(),
         })
     }
 
@@ -105,23 +153,138 @@ impl Screen {
     ///   ...
     /// }
     /// ```
-    pub fn build(driver: WebDriver) -> WebDriverResult<Self> {
+    pub fn build(driver: WebDriver) -> TestingLibraryResult<Self> {
         Ok(Screen {
             driver,
             within_element: None,
             configure_options: None,
+            frames: Vec::new(),
+            within_shadow_root: false,
+            pierce_shadow: false,
+            custom_queries: Vec::new(),
         })
     }
 
+    /// Creates a new `Screen` on the session of an element, without loading the testing library script
+    pub(crate) fn from_handle(handle: Arc<SessionHandle>) -> Self {
+        Screen {
+            driver: WebDriver { handle },
+            within_element: None,
+            configure_options: None,
+            frames: Vec::new(),
+            within_shadow_root: false,
+            pierce_shadow: false,
+            custom_queries: Vec::new(),
+        }
+    }
+
     /// Creates a new `Screen` wich will be scoped to a specific element
     pub fn within(&self, element: WebElement) -> Screen {
         Screen {
-            driver: self.driver.clone(),
             within_element: Some(element),
-            configure_options: self.configure_options.clone(),
+            within_shadow_root: false,
+            ..self.clone()
         }
     }
 
+    /// Creates a new `Screen` scoped to the open shadow root of the host element
+    pub async fn within_shadow_root(&self, host: &WebElement) -> TestingLibraryResult<Screen> {
+        let shadow_root = host.get_shadow_root().await?;
+
+        Ok(Screen {
+            within_element: Some(shadow_root),
+            within_shadow_root: true,
+            ..self.clone()
+        })
+    }
+
+    /// Makes queries also look inside the open shadow roots of the container, recursively
+    pub fn pierce_shadow(mut self, pierce_shadow: bool) -> Self {
+        self.pierce_shadow = pierce_shadow;
+        self
+    }
+
+    /// Creates a new `Screen` querying the document of a frame, selectors are resolved with `get`.
+    /// Each call enters the frame, injects the testing library script if missing and goes back
+    /// to the parent context, so returned elements can only be used by this screen
+    /// (e.g. with `user`, `fire_event` or `expect`) or after entering the frame.
+    pub async fn within_frame(&self, frame: impl Into<Target>) -> TestingLibraryResult<Screen> {
+        let frame = frame.into().resolve(self).await?;
+        let mut frames = self.frames.clone();
+        frames.push(frame);
+
+        Ok(Screen {
+            within_element: None,
+            within_shadow_root: false,
+            frames,
+            ..self.clone()
+        })
+    }
+
+    /// Queries all the elements matching the selector in the document and its same-origin frames,
+    /// recursively. Elements of frames can only be used after entering their frame.
+    pub async fn query_all_frames(
+        &self,
+        selector: impl Into<By>,
+    ) -> TestingLibraryResult<Vec<WebElement>> {
+        let selector = selector.into();
+        let mut elements = self.query_all(selector.clone()).await?;
+
+        let frames = self
+            .query_executor()
+            .execute_catching(
+                FRAMES_SCRIPT,
+                vec![self.query_executor().container_argument()?],
+            )
+            .await?
+            .elements()?;
+        for frame in frames {
+            let frame_screen = self.within_frame(frame).await?;
+            elements.extend(Box::pin(frame_screen.query_all_frames(selector.clone())).await?);
+        }
+
+        Ok(elements)
+    }
+
+    /// Registers a custom query used with `By::custom(name, ..)`, like Testing Library `buildQueries`.
+    /// `query_all` is a JavaScript function body returning the matching elements with
+    /// `container`, `value` and `options` in scope. The get, query and find variants are built
+    /// from it, with default error messages when `error_messages` is None.
+    /// The query is installed again in the page whenever it is missing, e.g. after a navigation,
+    /// and registering a name again replaces its query. Names of built-in queries like `Role`
+    /// are rejected with `TestingLibraryError::BuiltInQuery`.
+    pub async fn register_query(
+        mut self,
+        name: impl Into<String>,
+        query_all: impl Into<String>,
+        error_messages: Option<QueryErrorMessages>,
+    ) -> TestingLibraryResult<Self> {
+        let name = name.into();
+        if custom_query::BUILT_IN_QUERIES.contains(&name.as_str()) {
+            return Err(TestingLibraryError::BuiltInQuery(name));
+        }
+        let query = CustomQuery {
+            error_messages: error_messages.unwrap_or_else(|| QueryErrorMessages::for_query(&name)),
+            name,
+            query_all: query_all.into(),
+        };
+
+        self.query_executor()
+            .execute_catching(
+                &format!(
+                    "{INSTALL_QUERY_SCRIPT}{}",
+                    custom_query::REGISTER_QUERY_SCRIPT
+                ),
+                vec![serde_json::to_value(&query)?],
+            )
+            .await?;
+
+        self.custom_queries
+            .retain(|registered| registered.name != query.name);
+        self.custom_queries.push(query);
+        Ok(self)
+    }
+
     /// Configure the testing library options
     pub fn configure(mut self, options: configure::Options) -> Self {
         self.configure_options = Some(options);
@@ -130,111 +293,401 @@ impl Screen {
 
     /// Unified get method that accepts a Selector enum and returns a single WebElement
     /// Throws an error if no elements match or if more than one match is found
-    pub async fn get(&self, selector: impl Into<By>) -> WebDriverResult<WebElement> {
+    pub async fn get(&self, selector: impl Into<By>) -> TestingLibraryResult<WebElement> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        self.query_executor()
-            .execute_query(
-                "getBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                false,
-            )
+        Ok(self
+            .query_executor()
+            .execute_query("getBy", &selector)
             .await?
-            .element()
+            .element()?)
     }
 
     /// Unified get_all method that accepts a Selector enum and returns all matching WebElements
     /// Throws an error if no elements match
-    pub async fn get_all(&self, selector: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+    pub async fn get_all(&self, selector: impl Into<By>) -> TestingLibraryResult<Vec<WebElement>> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        self.query_executor()
-            .execute_query(
-                "getAllBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                false,
-            )
+        Ok(self
+            .query_executor()
+            .execute_query("getAllBy", &selector)
             .await?
-            .elements()
+            .elements()?)
     }
 
     /// Unified query method that accepts a Selector enum and returns a single WebElement
     /// Returns None if no elements match
-    pub async fn query(&self, selector: impl Into<By>) -> WebDriverResult<Option<WebElement>> {
+    pub async fn query(&self, selector: impl Into<By>) -> TestingLibraryResult<Option<WebElement>> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        let mut elements = self
+        let result = self
             .query_executor()
-            .execute_query(
-                "queryBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                true,
-            )
-            .await?
-            .elements()?;
+            .execute_query("queryBy", &selector)
+            .await?;
 
-        if elements.is_empty() {
+        if result.json().is_null() {
             return Ok(None);
         }
 
-        Ok(Some(elements.remove(0)))
+        Ok(Some(result.element()?))
     }
 
     /// Unified query_all method that accepts a Selector enum and returns all matching WebElements
     /// Returns empty Vec if no elements match
-    pub async fn query_all(&self, selector: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+    pub async fn query_all(
+        &self,
+        selector: impl Into<By>,
+    ) -> TestingLibraryResult<Vec<WebElement>> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        self.query_executor()
-            .execute_query(
-                "queryAllBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                false,
-            )
+        Ok(self
+            .query_executor()
+            .execute_query("queryAllBy", &selector)
             .await?
-            .elements()
+            .elements()?)
     }
 
     /// Unified find method that accepts a Selector enum and returns a single WebElement
     /// Waits for the element to appear and throws an error if not found
-    pub async fn find(&self, selector: impl Into<By>) -> WebDriverResult<WebElement> {
+    pub async fn find(&self, selector: impl Into<By>) -> TestingLibraryResult<WebElement> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        self.query_executor()
-            .execute_query(
-                "findBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                false,
-            )
+        Ok(self
+            .query_executor()
+            .execute_find_query("findBy", &selector, None)
             .await?
-            .element()
+            .element()?)
     }
 
     /// Unified find_all method that accepts a Selector enum and returns all matching WebElements
     /// Waits for elements to appear and throws an error if none are found
-    pub async fn find_all(&self, selector: impl Into<By>) -> WebDriverResult<Vec<WebElement>> {
+    pub async fn find_all(&self, selector: impl Into<By>) -> TestingLibraryResult<Vec<WebElement>> {
         let selector = selector.into();
-        let options_json = selector.options_json()?;
-        self.query_executor()
-            .execute_query(
-                "findAllBy",
-                selector.function_suffix(),
-                selector.value(),
-                options_json,
-                false,
-            )
+        Ok(self
+            .query_executor()
+            .execute_find_query("findAllBy", &selector, None)
+            .await?
+            .elements()?)
+    }
+
+    /// Same as `find` with a timeout, interval and mutation observer options for this call only
+    pub async fn find_with(
+        &self,
+        selector: impl Into<By>,
+        wait_options: WaitOptions,
+    ) -> TestingLibraryResult<WebElement> {
+        let selector = selector.into();
+        Ok(self
+            .query_executor()
+            .execute_find_query("findBy", &selector, Some(&wait_options))
             .await?
-            .elements()
+            .element()?)
+    }
+
+    /// Same as `find_all` with a timeout, interval and mutation observer options for this call only
+    pub async fn find_all_with(
+        &self,
+        selector: impl Into<By>,
+        wait_options: WaitOptions,
+    ) -> TestingLibraryResult<Vec<WebElement>> {
+        let selector = selector.into();
+        Ok(self
+            .query_executor()
+            .execute_find_query("findAllBy", &selector, Some(&wait_options))
+            .await?
+            .elements()?)
+    }
+
+    /// Calls the callback with this screen until it succeeds, like Testing Library `waitFor`
+    /// Fails with `TestingLibraryError::WaitTimeout` after `async_util_timeout` (1000ms by default)
+    pub async fn wait_for<F, Fut, T>(&self, callback: F) -> TestingLibraryResult<T>
+    where
+        F: FnMut(Screen) -> Fut,
+        Fut: Future<Output = TestingLibraryResult<T>>,
+    {
+        self.wait_for_with(callback, WaitForOptions::default())
+            .await
+    }
+
+    /// Calls the callback with this screen until it succeeds, with custom timeout, interval and on_timeout
+    pub async fn wait_for_with<F, Fut, T>(
+        &self,
+        mut callback: F,
+        options: WaitForOptions,
+    ) -> TestingLibraryResult<T>
+    where
+        F: FnMut(Screen) -> Fut,
+        Fut: Future<Output = TestingLibraryResult<T>>,
+    {
+        wait::wait_until(
+            options,
+            wait::async_util_timeout(self.configure_options.as_ref()),
+            || callback(self.clone()),
+        )
+        .await
+    }
+
+    /// Retries assertions until they pass, the timeout defaults to `async_util_timeout`
+    pub fn eventually(&self, timeout: Option<Duration>) -> Eventually {
+        Eventually::new(self.clone(), timeout)
+    }
+
+    /// Starts an assertion on the element, `eventually` retries default to `async_util_timeout`
+    pub fn expect(&self, element: &WebElement) -> Expect {
+        Expect::new(self.clone(), element)
+    }
+
+    /// Waits until no element matches the selector, like Testing Library `waitForElementToBeRemoved`
+    /// Fails right away if no element matches when called
+    pub async fn wait_for_element_to_be_removed(
+        &self,
+        selector: impl Into<By>,
+    ) -> TestingLibraryResult<()> {
+        self.wait_for_element_to_be_removed_with(selector, WaitForOptions::default())
+            .await
+    }
+
+    /// Waits until no element matches the selector, with custom timeout, interval and on_timeout
+    pub async fn wait_for_element_to_be_removed_with(
+        &self,
+        selector: impl Into<By>,
+        options: WaitForOptions,
+    ) -> TestingLibraryResult<()> {
+        let selector = selector.into();
+        let failure = |message: &str| {
+            Box::new(QueryFailure::new(
+                selector.clone(),
+                self.within_element.clone(),
+                message,
+            ))
+        };
+
+        if self.query_all(selector.clone()).await?.is_empty() {
+            return Err(TestingLibraryError::NoElementFound(failure(
+                "The element(s) given to waitForElementToBeRemoved are already removed. waitForElementToBeRemoved requires that the element(s) exist(s) before waiting for removal.",
+            )));
+        }
+
+        wait::wait_until(
+            options,
+            wait::async_util_timeout(self.configure_options.as_ref()),
+            || async {
+                let elements = self.query_all(selector.clone()).await?;
+                if elements.is_empty() {
+                    Ok(())
+                } else {
+                    Err(TestingLibraryError::ElementsStillPresent(failure(
+                        &format!("{} element(s) still match the selector", elements.len()),
+                    )))
+                }
+            },
+        )
+        .await
+    }
+
+    /// Realistic user interactions (click, type, tab...) backed by `@testing-library/user-event`
+    pub fn user(&self) -> UserEvent {
+        self.user_with(UserEventOptions::default())
+    }
+
+    /// Realistic user interactions with custom user-event setup options
+    pub fn user_with(&self, options: UserEventOptions) -> UserEvent {
+        UserEvent::new(self.clone(), options)
+    }
+
+    /// Fires a DOM event on the element with Testing Library `fireEvent`
+    /// Returns false if a listener cancelled the event
+    pub async fn fire_event(
+        &self,
+        element: &WebElement,
+        event: Event,
+    ) -> TestingLibraryResult<bool> {
+        let arguments = vec![
+            Value::from(event.name()),
+            element.to_json()?,
+            event.init()?,
+            event.data_transfer()?,
+        ];
+
+        let result = self
+            .query_executor()
+            .execute_catching(fire_event::FIRE_EVENT_SCRIPT, arguments)
+            .await?;
+        Ok(result.json().as_bool().unwrap_or(true))
+    }
+
+    /// Drags the source element onto the target with the HTML5 drag and drop events
+    /// (dragstart, dragenter, dragover, drop, dragend), selectors are resolved with `get`
+    /// Returns false if the target did not accept the drop
+    pub async fn drag(
+        &self,
+        source: impl Into<Target>,
+        target: impl Into<Target>,
+    ) -> TestingLibraryResult<bool> {
+        let source = source.into().resolve(self).await?;
+        let target = target.into().resolve(self).await?;
+
+        let result = self
+            .query_executor()
+            .execute_catching(
+                drag::DRAG_SCRIPT,
+                vec![source.to_json()?, target.to_json()?],
+            )
+            .await?;
+        Ok(result.json().as_bool().unwrap_or_default())
+    }
+
+    /// Computes the accessible name of the element, as used by `ByRoleOptions::name`
+    pub async fn accessible_name(&self, element: &WebElement) -> TestingLibraryResult<String> {
+        self.compute_accessibility("computeAccessibleName", element)
+            .await
+    }
+
+    /// Computes the accessible description of the element, as used by `ByRoleOptions::description`
+    pub async fn accessible_description(
+        &self,
+        element: &WebElement,
+    ) -> TestingLibraryResult<String> {
+        self.compute_accessibility("computeAccessibleDescription", element)
+            .await
+    }
+
+    /// Runs a dom-accessibility-api computation on the element
+    async fn compute_accessibility(
+        &self,
+        compute: &str,
+        element: &WebElement,
+    ) -> TestingLibraryResult<String> {
+        let result = self
+            .query_executor()
+            .execute_catching(
+                accessibility::ACCESSIBILITY_SCRIPT,
+                vec![Value::from(compute), element.to_json()?],
+            )
+            .await?;
+        Ok(result.json().as_str().unwrap_or_default().to_string())
+    }
+
+    /// Formats the element, or the `within` element or document body when None, with Testing Library `prettyDOM`
+    pub async fn pretty_dom(
+        &self,
+        element: Option<&WebElement>,
+        options: PrettyDomOptions,
+    ) -> TestingLibraryResult<String> {
+        let executor = self.query_executor();
+        let element = match element {
+            Some(element) => element.to_json()?,
+            None => executor.container_argument()?,
+        };
+        let max_length = options
+            .max_length
+            .unwrap_or_else(|| self.debug_print_limit());
+
+        let result = executor
+            .execute_catching(
+                debug::PRETTY_DOM_SCRIPT,
+                vec![
+                    element,
+                    Value::from(max_length),
+                    serde_json::to_value(options)?,
+                ],
+            )
+            .await?;
+        Ok(result.json().as_str().unwrap_or_default().to_string())
+    }
+
+    /// Number of characters printed by `prettyDOM` when no max length is given
+    fn debug_print_limit(&self) -> usize {
+        self.configure_options
+            .as_ref()
+            .and_then(|options| options.debug_print_limit)
+            .unwrap_or(debug::DEFAULT_DEBUG_PRINT_LIMIT)
+    }
+
+    /// Prints the DOM of the screen with `pretty_dom`, to a `tracing` event with the `tracing` feature
+    /// and to stderr otherwise
+    pub async fn debug(&self) -> TestingLibraryResult<()> {
+        let dom = self.pretty_dom(None, PrettyDomOptions::default()).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!("{dom}");
+        #[cfg(not(feature = "tracing"))]
+        eprintln!("{dom}");
+
+        Ok(())
+    }
+
+    /// Lists the elements of the screen by implicit or explicit role, like Testing Library `getRoles`
+    pub async fn roles(&self) -> TestingLibraryResult<BTreeMap<String, Vec<WebElement>>> {
+        self.roles_with(RolesOptions::default()).await
+    }
+
+    /// Lists the elements of the screen by role, with the hidden option
+    pub async fn roles_with(
+        &self,
+        options: RolesOptions,
+    ) -> TestingLibraryResult<BTreeMap<String, Vec<WebElement>>> {
+        let result = self.execute_roles_script("getRoles", options).await?;
+
+        let mut roles = BTreeMap::new();
+        if let Some(elements_by_role) = result.json().as_object() {
+            for (role, elements) in elements_by_role {
+                let elements =
+                    ScriptRet::new(self.driver.handle.clone(), elements.clone()).elements()?;
+                roles.insert(role.clone(), elements);
+            }
+        }
+        Ok(roles)
+    }
+
+    /// Returns the roles of the screen with their accessible names and elements, like Testing Library `logRoles`
+    pub async fn log_roles(&self) -> TestingLibraryResult<String> {
+        self.log_roles_with(RolesOptions::default()).await
+    }
+
+    /// Returns the roles of the screen with their accessible names and elements, with the hidden option
+    pub async fn log_roles_with(&self, options: RolesOptions) -> TestingLibraryResult<String> {
+        let result = self.execute_roles_script("logRoles", options).await?;
+        Ok(result.json().as_str().unwrap_or_default().to_string())
+    }
+
+    /// Checks if the element is excluded from the accessibility tree, like Testing Library `isInaccessible`
+    pub async fn is_inaccessible(&self, element: &WebElement) -> TestingLibraryResult<bool> {
+        let result = self
+            .query_executor()
+            .execute_catching(roles::IS_INACCESSIBLE_SCRIPT, vec![element.to_json()?])
+            .await?;
+        Ok(result.json().as_bool().unwrap_or_default())
+    }
+
+    /// Runs `getRoles` or `logRoles` on the screen container
+    async fn execute_roles_script(
+        &self,
+        helper: &str,
+        options: RolesOptions,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let executor = self.query_executor();
+        let arguments = vec![
+            Value::from(helper),
+            executor.container_argument()?,
+            serde_json::to_value(options)?,
+        ];
+        executor
+            .execute_catching(roles::ROLES_SCRIPT, arguments)
+            .await
+    }
+
+    /// Suggests the most accessible query matching the element, like Testing Library `getSuggestedQuery`,
+    /// restricted to a query method when set. Returns None when no query can find the element.
+    pub async fn suggested_query(
+        &self,
+        element: &WebElement,
+        method: Option<QueryMethod>,
+    ) -> TestingLibraryResult<Option<By>> {
+        let arguments = vec![
+            element.to_json()?,
+            Value::from(method.map(|method| method.as_str())),
+        ];
+        let result = self
+            .query_executor()
+            .execute_catching(suggest::SUGGESTED_QUERY_SCRIPT, arguments)
+            .await?;
+        suggest::suggestion_to_by(result.json())
     }
 
     /// Logs and returns a URL that can be opened in a browser for debugging using testing-playground
@@ -242,7 +695,7 @@ impl Screen {
     pub async fn log_testing_playground_url(
         &self,
         element: Option<WebElement>,
-    ) -> WebDriverResult<String> {
+    ) -> TestingLibraryResult<String> {
         let (script, arguments) = match element {
             Some(element) => (
                 "return window.__TL__.logTestingPlaygroundURL(arguments[0]);",
@@ -257,14 +710,14 @@ impl Screen {
             .json()
             .as_str()
             .ok_or_else(|| {
-                WebDriverError::Json(
+                TestingLibraryError::ScriptSerialization(
                     "logTestingPlaygroundURL returned non-string value".to_string(),
                 )
             })
             .map(|s| s.to_string())
     }
 
-    async fn load_testing_library(driver: &WebDriver) -> WebDriverResult<()> {
+    async fn load_testing_library(driver: &WebDriver) -> TestingLibraryResult<()> {
         // Load the testing library script in the browser
         let testing_library = include_str!("../js/testing-library.js");
         driver.execute(testing_library, vec![]).await?;
@@ -274,182 +727,351 @@ impl Screen {
 
     /// Get a query executor configured with current options
     fn query_executor(&self) -> QueryExecutor {
-        QueryExecutor::new(
-            self.driver.clone(),
-            self.within_element.clone(),
-            self.configure_options.clone(),
-        )
+        QueryExecutor {
+            driver: self.driver.clone(),
+            within_element: self.within_element.clone(),
+            configure_options: self.configure_options.clone(),
+            frames: self.frames.clone(),
+            within_shadow_root: self.within_shadow_root,
+            pierce_shadow: self.pierce_shadow,
+            custom_queries: self.custom_queries.clone(),
+        }
     }
 }
 
+/// Script listing the same-origin frames of the container, the container (`null` for the document)
+/// comes from `arguments`. Cross-origin frames have no `contentDocument`.
+const FRAMES_SCRIPT: &str = r#"
+    const [container] = arguments;
+    const frames = Array.from((container || document).querySelectorAll("iframe, frame")).filter((frame) => {
+        try {
+            return frame.contentDocument !== null;
+        } catch {
+            return false;
+        }
+    });
+    return frames;
+"#;
+
 /// Helper struct for executing Testing Library queries
 #[derive(Debug, Clone)]
 struct QueryExecutor {
     driver: WebDriver,
     within_element: Option<WebElement>,
     configure_options: Option<configure::Options>,
+    frames: Vec<WebElement>,
+    within_shadow_root: bool,
+    pierce_shadow: bool,
+    custom_queries: Vec<CustomQuery>,
 }
 
 impl QueryExecutor {
-    fn new(
-        driver: WebDriver,
-        within_element: Option<WebElement>,
-        configure_options: Option<configure::Options>,
-    ) -> Self {
-        Self {
-            driver,
-            within_element,
-            configure_options,
-        }
-    }
+    const LIBRARY_NOT_FOUND_ERROR: &str = "Testing Library not found";
+
+    /// Extra time given to find scripts on top of their wait before WebDriver aborts them
+    const SCRIPT_TIMEOUT_MARGIN: Duration = Duration::from_secs(2);
+
+    /// WebDriver key of shadow root references, scripts receive them as `ShadowRoot` objects
+    const SHADOW_ROOT_KEY: &str = "shadow-6066-11e4-a52e-4f735466cecf";
+
+    /// Script running a Testing Library query with the method name, the container (`null` for
+    /// the document), the value, the options, the wait options of find queries, whether open
+    /// shadow roots are searched and the custom query to install if the selector uses one.
+    /// Regexes, function matchers and normalizers are rebuilt in the page by `REVIVE_SCRIPT`.
+    /// Role states Testing Library does not filter on (`mixed`, `disabled` and `invalid`)
+    /// are folded into the `name` matcher.
+    const QUERY_SCRIPT: &str = r#"
+        const [method, container, value, options, waitOptions, pierceShadow, customQuery] = arguments;
+        const withRoleStates = (options) => {
+            const { checked, pressed, disabled, invalid, ...rest } = options;
+            const filters = [];
+            if (checked === "mixed") {
+                filters.push(["checked: mixed", (element) =>
+                    element.indeterminate === true || element.getAttribute("aria-checked") === "mixed"]);
+            } else if (checked !== undefined) {
+                rest.checked = checked;
+            }
+            if (pressed === "mixed") {
+                filters.push(["pressed: mixed", (element) => element.getAttribute("aria-pressed") === "mixed"]);
+            } else if (pressed !== undefined) {
+                rest.pressed = pressed;
+            }
+            if (disabled !== undefined) {
+                filters.push([`disabled: ${disabled}`, (element) =>
+                    (element.matches(":disabled") || element.closest('[aria-disabled="true"]') !== null) === disabled]);
+            }
+            if (invalid !== undefined) {
+                filters.push([`invalid: ${invalid}`, (element) => {
+                    const state = element.getAttribute("aria-invalid");
+                    return (state !== null && state !== "" && state !== "false") === invalid;
+                }]);
+            }
+            if (filters.length === 0) return rest;
+
+            const { name } = rest;
+            const matchesName = (accessibleName, element) => {
+                if (name === undefined) return true;
+                if (typeof name === "string") return accessibleName === name;
+                if (name instanceof RegExp) {
+                    name.lastIndex = 0;
+                    return name.test(accessibleName);
+                }
+                return name(accessibleName, element);
+            };
+            rest.name = (accessibleName, element) =>
+                filters.every(([, filter]) => filter(element)) && matchesName(accessibleName, element);
+            const description = filters.map(([label]) => label).join(", ");
+            rest.name.toString = () =>
+                name === undefined ? `(${description})` : `${typeof name === "string" ? JSON.stringify(name) : name} (${description})`;
+            return rest;
+        };
+        // Runs the queryAllBy query of the method in the container and every open shadow root below it,
+        // the other variants are built on top of it like Testing Library custom queries
+        const piercing = (method) => {
+            const [, variant, type] = method.match(/^(queryAll|query|getAll|get|findAll|find)By(.+)$/);
+            const roots = (root) => [
+                root,
+                ...Array.from(root.querySelectorAll("*"))
+                    .filter((element) => element.shadowRoot)
+                    .flatMap((element) => roots(element.shadowRoot)),
+            ];
+            const queryAll = (container, ...args) =>
+                Array.from(new Set(roots(container).flatMap((root) => window.__TL__[`queryAllBy${type}`](root, ...args))));
+            const [query, getAll, get, findAll, find] = window.__TL__.buildQueries(
+                queryAll,
+                (_, value) => `Found multiple elements by ${type}: ${value}, including open shadow roots`,
+                (_, value) => `Unable to find an element by ${type}: ${value}, including open shadow roots`,
+            );
+            return { queryAll, query, getAll, get, findAll, find }[variant];
+        };
+        installQuery(customQuery);
+        const queryOptions = method.endsWith("ByRole") ? withRoleStates(revive(options)) : revive(options);
+        return (pierceShadow ? piercing(method) : window.__TL__[method])(
+            container || document, revive(value), queryOptions, waitOptions || undefined);
+    "#;
 
     /// Execute a basic Testing Library script with retry logic
-    pub async fn execute(&self, script: &str, arguments: Vec<Value>) -> WebDriverResult<ScriptRet> {
-        let wrapped_script = self.wrap_load_retry(script);
-        self.execute_and_retry_if_library_not_found(&wrapped_script, arguments)
-            .await
+    pub async fn execute(
+        &self,
+        script: &str,
+        arguments: Vec<Value>,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let wrapped_script = Self::wrap_script(script);
+        let arguments = self.with_configure_argument(arguments)?;
+
+        // Leave the frames entered so far even if entering the next one fails
+        let mut entered_frames = 0;
+        let mut result = Ok(());
+        for frame in &self.frames {
+            result = frame.clone().enter_frame().await;
+            if result.is_err() {
+                break;
+            }
+            entered_frames += 1;
+        }
+        let result = match result {
+            Ok(()) => {
+                self.execute_and_retry_if_library_not_found(&wrapped_script, arguments)
+                    .await
+            }
+            Err(error) => Err(error.into()),
+        };
+        for _ in 0..entered_frames {
+            self.driver.enter_parent_frame().await?;
+        }
+
+        result
     }
 
     /// Execute a Testing Library query
     pub async fn execute_query(
         &self,
         method_prefix: &str,
-        function_suffix: &str,
-        value: &str,
-        options_json: Option<String>,
-        with_null_filter: bool,
-    ) -> WebDriverResult<ScriptRet> {
-        let method_name = format!("{method_prefix}{function_suffix}");
-        let (container, arguments) = self.container_and_arguments()?;
-
-        let script = self.build_and_wrap(
-            &method_name,
-            container,
-            value,
-            options_json.as_deref(),
-            with_null_filter,
-        );
-
-        self.execute_and_retry_if_library_not_found(&script, arguments)
-            .await
+        selector: &By,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let arguments = self.query_arguments(method_prefix, selector, Value::Null)?;
+        let result = self.execute(&Self::query_script(), arguments).await?;
+        self.query_result(method_prefix, selector, result)
     }
 
-    /// Build and wrap a Testing Library script in one call
-    fn build_and_wrap(
+    /// Execute a Testing Library find query, raising the WebDriver script timeout
+    /// for the duration of the call if it is shorter than the wait
+    pub async fn execute_find_query(
         &self,
-        method_name: &str,
-        container: &str,
-        value: &str,
-        options_json: Option<&str>,
-        with_null_filter: bool,
-    ) -> String {
-        let script = self.query_script(
-            method_name,
-            container,
-            value,
-            options_json,
-            with_null_filter,
-        );
-        self.wrap_load_retry(&self.wrap_configure(&script))
+        method_prefix: &str,
+        selector: &By,
+        wait_options: Option<&WaitOptions>,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let wait_options_json = match wait_options {
+            Some(wait_options) => wait_options.to_json_value()?,
+            None => Value::Null,
+        };
+        let arguments = self.query_arguments(method_prefix, selector, wait_options_json)?;
+
+        let timeout = wait_options
+            .and_then(|wait_options| wait_options.timeout)
+            .unwrap_or_else(|| wait::async_util_timeout(self.configure_options.as_ref()));
+        let previous_script_timeout = self.raise_script_timeout(timeout).await?;
+
+        let result = self.execute(&Self::query_script(), arguments).await;
+
+        if let Some(previous_script_timeout) = previous_script_timeout {
+            self.driver
+                .set_script_timeout(previous_script_timeout)
+                .await?;
+        }
+        self.query_result(method_prefix, selector, result?)
+    }
+
+    /// Query script preceded by the `revive` function it relies on
+    fn query_script() -> String {
+        Self::catching_script(&format!(
+            "{REVIVE_SCRIPT}{INSTALL_QUERY_SCRIPT}{}",
+            Self::QUERY_SCRIPT
+        ))
     }
 
-    fn query_script(
+    /// Build the arguments of the query script
+    fn query_arguments(
         &self,
-        method_name: &str,
-        container: &str,
-        value: &str,
-        options_json: Option<&str>,
-        with_null_filter: bool,
-    ) -> String {
-        // Format the value - detect regex patterns and handle appropriately
-        let formatted_value = Self::format_query_value(value);
-
-        let base_call = match options_json {
-            Some(options) => {
-                format!("window.__TL__.{method_name}({container}, {formatted_value}, {options})")
-            }
-            None => {
-                format!("window.__TL__.{method_name}({container}, {formatted_value})")
-            }
-        };
+        method_prefix: &str,
+        selector: &By,
+        wait_options: Value,
+    ) -> TestingLibraryResult<Vec<Value>> {
+        let method_name = format!("{method_prefix}{}", selector.function_suffix());
+        Ok(vec![
+            Value::String(method_name),
+            self.container_argument()?,
+            serde_json::to_value(selector.value())?,
+            selector.options_json()?,
+            wait_options,
+            Value::Bool(self.pierce_shadow),
+            self.custom_query_argument(selector)?,
+        ])
+    }
 
-        let script = if with_null_filter {
-            // Transform null values to empty arrays easier to parse in Rust
-            format!("return [{base_call}].filter(n => n);")
-        } else {
-            format!("return {base_call};")
+    /// Get the custom query used by the selector, `null` for built-in queries
+    fn custom_query_argument(&self, selector: &By) -> TestingLibraryResult<Value> {
+        let By::Custom(name, _, _) = selector else {
+            return Ok(Value::Null);
         };
-
-        // Process any regex markers in the final script
-        process_raw_javascript_markers(&script)
+        let query = self
+            .custom_queries
+            .iter()
+            .find(|query| &query.name == name)
+            .ok_or_else(|| TestingLibraryError::UnknownQuery(name.clone()))?;
+        Ok(serde_json::to_value(query)?)
     }
 
-    /// Format a query value, detecting regex patterns and handling them appropriately
-    fn format_query_value(value: &str) -> String {
-        if Self::is_regex_pattern(value) {
-            // It's a regex pattern - use the marker system for post-processing
-            format!("\"__RAW_JS__{value}\"")
-        } else {
-            // Regular string - quote it
-            // TODO: could be an issue if the value contains quotes
-            format!("'{value}'")
+    /// Make sure the WebDriver script timeout covers a wait of the given duration,
+    /// returns the previous script timeout if it had to be raised
+    async fn raise_script_timeout(
+        &self,
+        wait_timeout: Duration,
+    ) -> TestingLibraryResult<Option<Duration>> {
+        let required = wait_timeout + Self::SCRIPT_TIMEOUT_MARGIN;
+        match self.driver.get_timeouts().await?.script() {
+            Some(script_timeout) if script_timeout < required => {
+                self.driver.set_script_timeout(required).await?;
+                Ok(Some(script_timeout))
+            }
+            _ => Ok(None),
         }
     }
 
-    /// Detect if a value is a regex pattern (starts and ends with '/')
-    fn is_regex_pattern(value: &str) -> bool {
-        if value.starts_with('/') && value.len() > 2 {
-            if let Some(last_slash) = value.rfind('/') {
-                return last_slash > 0; // Ensure it's not just the opening slash
-            }
+    /// Execute a script returning its result or a promise of it, turning thrown errors and
+    /// rejections into `TestingLibraryError::Javascript`
+    pub async fn execute_catching(
+        &self,
+        script: &str,
+        arguments: Vec<Value>,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let result = self
+            .execute(&Self::catching_script(script), arguments)
+            .await?;
+        let json = result.json();
+        if let Some(error) = json.get("error") {
+            return Err(TestingLibraryError::Javascript {
+                name: error["name"].as_str().unwrap_or_default().to_string(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
         }
-        false
+
+        Ok(ScriptRet::new(
+            self.driver.handle.clone(),
+            json["result"].clone(),
+        ))
     }
 
-    const LIBRARY_NOT_FOUND_ERROR: &str = "Testing Library not found";
+    /// Unwrap the result of a query script, turning returned errors into `TestingLibraryError`
+    fn query_result(
+        &self,
+        method_prefix: &str,
+        selector: &By,
+        result: ScriptRet,
+    ) -> TestingLibraryResult<ScriptRet> {
+        let json = result.json();
+        if let Some(error) = json.get("error") {
+            return Err(TestingLibraryError::from_query_error(
+                method_prefix,
+                selector,
+                self.within_element.clone(),
+                error["name"].as_str().unwrap_or_default(),
+                error["message"].as_str().unwrap_or_default(),
+            ));
+        }
 
-    fn wrap_load_retry(&self, script: &str) -> String {
-        let configured_script = if let Some(ref options) = self.configure_options {
-            if let Ok(options_json) = options.to_json_string() {
-                format!("window.__TL__.configure({options_json}); {script}")
-            } else {
-                script.to_string()
-            }
-        } else {
-            script.to_string()
-        };
+        Ok(ScriptRet::new(
+            self.driver.handle.clone(),
+            json["result"].clone(),
+        ))
+    }
 
+    /// Wrap a script so it checks the library is loaded and applies the configure options,
+    /// which are passed as the last argument and hidden from the wrapped script
+    fn wrap_script(script: &str) -> String {
         format!(
-            "if (!window.__TL__) return '{}'; {}",
+            "if (!window.__TL__) return '{}'; \
+             const configureOptions = arguments[arguments.length - 1]; \
+             if (configureOptions) window.__TL__.configure(configureOptions); \
+             return (function () {{ {} }}).apply(this, Array.prototype.slice.call(arguments, 0, -1));",
             Self::LIBRARY_NOT_FOUND_ERROR,
-            configured_script
+            script
         )
     }
 
-    fn wrap_configure(&self, script: &str) -> String {
-        let configured_script = if let Some(ref options) = self.configure_options {
-            if let Ok(options_json) = options.to_json_string() {
-                format!("window.__TL__.configure({options_json}); {script}")
-            } else {
-                script.to_string()
-            }
-        } else {
-            script.to_string()
-        };
-
+    /// Run the script in a promise chain returning `{result}`, or `{error}` when it throws or
+    /// rejects, so JavaScript errors can be told apart from WebDriver ones in Rust
+    fn catching_script(script: &str) -> String {
         format!(
-            "if (!window.__TL__) return '{}'; {}",
-            Self::LIBRARY_NOT_FOUND_ERROR,
-            configured_script
+            "return Promise.resolve() \
+                 .then(() => (function () {{ {script} }}).apply(this, arguments)) \
+                 .then((result) => ({{ result }}), \
+                       (error) => ({{ error: {{ name: error.name, message: error.message }} }}));"
         )
     }
 
-    /// Get container and arguments for script execution
-    fn container_and_arguments(&self) -> WebDriverResult<(&str, Vec<Value>)> {
-        if let Some(within_element) = &self.within_element {
-            Ok(("arguments[0]", vec![within_element.to_json()?]))
-        } else {
-            Ok(("document", vec![]))
+    /// Append the serialized configure options (or `null`) to the script arguments
+    fn with_configure_argument(
+        &self,
+        mut arguments: Vec<Value>,
+    ) -> TestingLibraryResult<Vec<Value>> {
+        let configure_options = match &self.configure_options {
+            Some(options) => serde_json::to_value(options)?,
+            None => Value::Null,
+        };
+        arguments.push(configure_options);
+        Ok(arguments)
+    }
+
+    /// Get the container argument, `null` meaning the whole document
+    fn container_argument(&self) -> TestingLibraryResult<Value> {
+        match &self.within_element {
+            Some(shadow_root) if self.within_shadow_root => Ok(serde_json::json!({
+                Self::SHADOW_ROOT_KEY: shadow_root.element_id().to_string()
+            })),
+            Some(within_element) => Ok(within_element.to_json()?),
+            None => Ok(Value::Null),
         }
     }
 
@@ -458,17 +1080,24 @@ impl QueryExecutor {
         &self,
         script: &str,
         arguments: Vec<Value>,
-    ) -> WebDriverResult<ScriptRet> {
+    ) -> TestingLibraryResult<ScriptRet> {
         let result = self.driver.execute(script, arguments.clone()).await?;
+        if !Self::is_library_not_found(&result) {
+            return Ok(result);
+        }
 
-        let string_value = result.json().as_str();
-        if string_value == Some(Self::LIBRARY_NOT_FOUND_ERROR) {
-            Screen::load_testing_library(&self.driver).await?;
-            return self.driver.execute(script, arguments).await;
+        Screen::load_testing_library(&self.driver).await?;
+        let result = self.driver.execute(script, arguments).await?;
+        if Self::is_library_not_found(&result) {
+            return Err(TestingLibraryError::LibraryNotInjected);
         }
 
         Ok(result)
     }
+
+    fn is_library_not_found(result: &ScriptRet) -> bool {
+        result.json().as_str() == Some(Self::LIBRARY_NOT_FOUND_ERROR)
+    }
 }
 
 /// Options enum for unified option handling
@@ -490,20 +1119,23 @@ pub enum Options {
     Title(ByTitleOptions),
     /// Test ID query options
     TestId(ByTestIdOptions),
+    /// Custom query options, passed as is to the registered query
+    Custom(Value),
 }
 
 impl Options {
-    /// Serialize the options to JSON string
-    fn to_json_string(&self) -> Result<String, serde_json::Error> {
+    /// Serialize the options to a JSON value
+    fn to_json_value(&self) -> Result<Value, serde_json::Error> {
         match self {
-            Options::Role(options) => options.to_json_string(),
-            Options::Text(options) => options.to_json_string(),
-            Options::LabelText(options) => options.to_json_string(),
-            Options::PlaceholderText(options) => options.to_json_string(),
-            Options::DisplayValue(options) => options.to_json_string(),
-            Options::AltText(options) => options.to_json_string(),
-            Options::Title(options) => options.to_json_string(),
-            Options::TestId(options) => options.to_json_string(),
+            Options::Role(options) => options.to_json_value(),
+            Options::Text(options) => options.to_json_value(),
+            Options::LabelText(options) => options.to_json_value(),
+            Options::PlaceholderText(options) => options.to_json_value(),
+            Options::DisplayValue(options) => options.to_json_value(),
+            Options::AltText(options) => options.to_json_value(),
+            Options::Title(options) => options.to_json_value(),
+            Options::TestId(options) => options.to_json_value(),
+            Options::Custom(options) => Ok(options.clone()),
         }
     }
 }
@@ -511,13 +1143,13 @@ impl Options {
 /// Fluent builder for role-based queries with comprehensive options
 #[derive(Debug, Clone)]
 pub struct RoleSelector {
-    value: String,
+    value: TextMatch,
     options: ByRoleOptions,
 }
 
 impl RoleSelector {
     /// Create a new RoleSelector with the given role value
-    pub fn new(value: impl Into<String>) -> Self {
+    pub fn new(value: impl Into<TextMatch>) -> Self {
         Self {
             value: value.into(),
             options: ByRoleOptions::default(),
@@ -531,18 +1163,18 @@ impl RoleSelector {
     }
 
     /// Set the name option - filter by accessible name
-    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/')
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        let name_str = name.into();
-        self.options.name = Some(TextMatch::from(name_str));
+    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/'),
+    /// or an explicit `TextMatch`
+    pub fn name(mut self, name: impl Into<TextMatch>) -> Self {
+        self.options.name = Some(name.into());
         self
     }
 
     /// Set the description option - filter by accessible description  
-    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/')
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        let desc_str = description.into();
-        self.options.description = Some(TextMatch::from(desc_str));
+    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/'),
+    /// or an explicit `TextMatch`
+    pub fn description(mut self, description: impl Into<TextMatch>) -> Self {
+        self.options.description = Some(description.into());
         self
     }
 
@@ -558,15 +1190,27 @@ impl RoleSelector {
         self
     }
 
-    /// Set the checked option - filter by checked state (aria-checked)
-    pub fn checked(mut self, checked: bool) -> Self {
-        self.options.checked = Some(checked);
+    /// Set the checked option - filter by checked state (aria-checked), `true`, `false` or `TriState::Mixed`
+    pub fn checked(mut self, checked: impl Into<TriState>) -> Self {
+        self.options.checked = Some(checked.into());
+        self
+    }
+
+    /// Set the pressed option - filter by pressed state (aria-pressed), `true`, `false` or `TriState::Mixed`
+    pub fn pressed(mut self, pressed: impl Into<TriState>) -> Self {
+        self.options.pressed = Some(pressed.into());
         self
     }
 
-    /// Set the pressed option - filter by pressed state (aria-pressed)
-    pub fn pressed(mut self, pressed: bool) -> Self {
-        self.options.pressed = Some(pressed);
+    /// Set the disabled option - filter by disabled state (disabled or aria-disabled)
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.options.disabled = Some(disabled);
+        self
+    }
+
+    /// Set the invalid option - filter by invalid state (aria-invalid)
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.options.invalid = Some(invalid);
         self
     }
 
@@ -613,17 +1257,16 @@ impl From<RoleSelector> for By {
     }
 }
 
-/// Fluent builder for simple queries that only support exact matching
+/// Fluent builder for simple queries that only support text matching options
 #[derive(Debug, Clone)]
 pub struct SimpleSelector {
-    value: String,
+    value: TextMatch,
     selector_type: SimpleSelectorType,
     options: SimpleOptions,
 }
 
 #[derive(Debug, Clone)]
 enum SimpleSelectorType {
-    Text,
     AltText,
     DisplayValue,
     PlaceholderText,
@@ -633,7 +1276,7 @@ enum SimpleSelectorType {
 
 impl SimpleSelector {
     /// Create a new SimpleSelector with the given value and type
-    fn new(value: impl Into<String>, selector_type: SimpleSelectorType) -> Self {
+    fn new(value: impl Into<TextMatch>, selector_type: SimpleSelectorType) -> Self {
         Self {
             value: value.into(),
             selector_type,
@@ -646,13 +1289,30 @@ impl SimpleSelector {
         self.options.exact = Some(exact);
         self
     }
+
+    /// Set the trim option - whether to trim whitespace around the text
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.options.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option - whether to collapse inner whitespace into a single space
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.options.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option - custom text normalization, replaces trim and collapse_whitespace
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.options.normalizer = Some(normalizer);
+        self
+    }
 }
 
 impl From<SimpleSelector> for By {
     fn from(selector: SimpleSelector) -> Self {
-        let options = if selector.options.exact.is_some() {
+        let options = if !selector.options.is_empty() {
             Some(match selector.selector_type {
-                SimpleSelectorType::Text => Options::Text(selector.options),
                 SimpleSelectorType::AltText => Options::AltText(selector.options),
                 SimpleSelectorType::DisplayValue => Options::DisplayValue(selector.options),
                 SimpleSelectorType::PlaceholderText => Options::PlaceholderText(selector.options),
@@ -664,7 +1324,6 @@ impl From<SimpleSelector> for By {
         };
 
         match selector.selector_type {
-            SimpleSelectorType::Text => By::Text(selector.value, options),
             SimpleSelectorType::AltText => By::AltText(selector.value, options),
             SimpleSelectorType::DisplayValue => By::DisplayValue(selector.value, options),
             SimpleSelectorType::PlaceholderText => By::PlaceholderText(selector.value, options),
@@ -674,16 +1333,80 @@ impl From<SimpleSelector> for By {
     }
 }
 
+/// Fluent builder for text queries with selector, exact and ignore options
+#[derive(Debug, Clone)]
+pub struct TextSelector {
+    value: TextMatch,
+    options: ByTextOptions,
+}
+
+impl TextSelector {
+    /// Create a new TextSelector with the given text value
+    pub fn new(value: impl Into<TextMatch>) -> Self {
+        Self {
+            value: value.into(),
+            options: ByTextOptions::default(),
+        }
+    }
+
+    /// Set the selector option - CSS selector to filter elements
+    pub fn selector(mut self, selector: impl Into<String>) -> Self {
+        self.options.selector = Some(selector.into());
+        self
+    }
+
+    /// Set the exact option - whether to use exact text matching
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.options.exact = Some(exact);
+        self
+    }
+
+    /// Set the ignore option - CSS selector of the elements to skip
+    pub fn ignore(mut self, ignore: impl Into<Ignore>) -> Self {
+        self.options.ignore = Some(ignore.into());
+        self
+    }
+
+    /// Set the trim option - whether to trim whitespace around the text
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.options.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option - whether to collapse inner whitespace into a single space
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.options.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option - custom text normalization, replaces trim and collapse_whitespace
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.options.normalizer = Some(normalizer);
+        self
+    }
+}
+
+impl From<TextSelector> for By {
+    fn from(selector: TextSelector) -> Self {
+        let options = if !selector.options.is_empty() {
+            Some(Options::Text(selector.options))
+        } else {
+            None
+        };
+        By::Text(selector.value, options)
+    }
+}
+
 /// Fluent builder for label text queries with selector and exact options
 #[derive(Debug, Clone)]
 pub struct LabelTextSelector {
-    value: String,
+    value: TextMatch,
     options: ByLabelTextOptions,
 }
 
 impl LabelTextSelector {
     /// Create a new LabelTextSelector with the given label text value
-    pub fn new(value: impl Into<String>) -> Self {
+    pub fn new(value: impl Into<TextMatch>) -> Self {
         Self {
             value: value.into(),
             options: ByLabelTextOptions::default(),
@@ -701,11 +1424,29 @@ impl LabelTextSelector {
         self.options.exact = Some(exact);
         self
     }
+
+    /// Set the trim option - whether to trim whitespace around the text
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.options.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option - whether to collapse inner whitespace into a single space
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.options.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option - custom text normalization, replaces trim and collapse_whitespace
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.options.normalizer = Some(normalizer);
+        self
+    }
 }
 
 impl From<LabelTextSelector> for By {
     fn from(selector: LabelTextSelector) -> Self {
-        let options = if selector.options.selector.is_some() || selector.options.exact.is_some() {
+        let options = if !selector.options.is_empty() {
             Some(Options::LabelText(selector.options))
         } else {
             None
@@ -715,46 +1456,128 @@ impl From<LabelTextSelector> for By {
 }
 
 impl By {
-    /// Create a role selector without options
-    pub fn role(value: &str) -> RoleSelector {
-        RoleSelector::new(value)
+    /// Create a role selector without options, from an `AriaRole` or a role name
+    pub fn role(role: impl Into<AriaRole>) -> RoleSelector {
+        RoleSelector::new(role.into())
+    }
+
+    /// Create a `button` role selector
+    pub fn button() -> RoleSelector {
+        By::role(AriaRole::Button)
+    }
+
+    /// Create a `link` role selector
+    pub fn link() -> RoleSelector {
+        By::role(AriaRole::Link)
+    }
+
+    /// Create a `heading` role selector filtered by heading level
+    pub fn heading(level: u8) -> RoleSelector {
+        By::role(AriaRole::Heading).level(level)
+    }
+
+    /// Create a `textbox` role selector
+    pub fn textbox() -> RoleSelector {
+        By::role(AriaRole::TextBox)
+    }
+
+    /// Create a `checkbox` role selector
+    pub fn checkbox() -> RoleSelector {
+        By::role(AriaRole::Checkbox)
+    }
+
+    /// Create a `radio` role selector
+    pub fn radio() -> RoleSelector {
+        By::role(AriaRole::Radio)
+    }
+
+    /// Create a `combobox` role selector
+    pub fn combobox() -> RoleSelector {
+        By::role(AriaRole::ComboBox)
+    }
+
+    /// Create a `listbox` role selector
+    pub fn listbox() -> RoleSelector {
+        By::role(AriaRole::ListBox)
+    }
+
+    /// Create an `option` role selector
+    pub fn option() -> RoleSelector {
+        By::role(AriaRole::Option)
+    }
+
+    /// Create a `dialog` role selector
+    pub fn dialog() -> RoleSelector {
+        By::role(AriaRole::Dialog)
+    }
+
+    /// Create an `img` role selector
+    pub fn img() -> RoleSelector {
+        By::role(AriaRole::Img)
+    }
+
+    /// Create a `list` role selector
+    pub fn list() -> RoleSelector {
+        By::role(AriaRole::List)
+    }
+
+    /// Create a `listitem` role selector
+    pub fn listitem() -> RoleSelector {
+        By::role(AriaRole::ListItem)
+    }
+
+    /// Create a `tab` role selector
+    pub fn tab() -> RoleSelector {
+        By::role(AriaRole::Tab)
+    }
+
+    /// Create a `slider` role selector
+    pub fn slider() -> RoleSelector {
+        By::role(AriaRole::Slider)
     }
 
     /// Create a text selector without options
-    pub fn text(value: &str) -> SimpleSelector {
-        SimpleSelector::new(value, SimpleSelectorType::Text)
+    pub fn text(value: impl Into<TextMatch>) -> TextSelector {
+        TextSelector::new(value)
     }
 
     /// Create a label text selector without options
-    pub fn label_text(value: &str) -> LabelTextSelector {
+    pub fn label_text(value: impl Into<TextMatch>) -> LabelTextSelector {
         LabelTextSelector::new(value)
     }
 
     /// Create a placeholder text selector without options
-    pub fn placeholder_text(value: &str) -> SimpleSelector {
+    pub fn placeholder_text(value: impl Into<TextMatch>) -> SimpleSelector {
         SimpleSelector::new(value, SimpleSelectorType::PlaceholderText)
     }
 
     /// Create a display value selector without options
-    pub fn display_value(value: &str) -> SimpleSelector {
+    pub fn display_value(value: impl Into<TextMatch>) -> SimpleSelector {
         SimpleSelector::new(value, SimpleSelectorType::DisplayValue)
     }
 
     /// Create an alt text selector without options
-    pub fn alt_text(value: &str) -> SimpleSelector {
+    pub fn alt_text(value: impl Into<TextMatch>) -> SimpleSelector {
         SimpleSelector::new(value, SimpleSelectorType::AltText)
     }
 
     /// Create a title selector without options
-    pub fn title(value: &str) -> SimpleSelector {
+    pub fn title(value: impl Into<TextMatch>) -> SimpleSelector {
         SimpleSelector::new(value, SimpleSelectorType::Title)
     }
 
     /// Create a test ID selector without options
-    pub fn test_id(value: &str) -> SimpleSelector {
+    pub fn test_id(value: impl Into<TextMatch>) -> SimpleSelector {
         SimpleSelector::new(value, SimpleSelectorType::TestId)
     }
 
+    /// Create a selector using a query registered with `Screen::register_query`,
+    /// options are passed as is to the query (`Value::Null` for none)
+    pub fn custom(name: impl Into<String>, value: impl Into<TextMatch>, options: Value) -> By {
+        let options = (!options.is_null()).then_some(Options::Custom(options));
+        By::Custom(name.into(), value.into(), options)
+    }
+
     /// Returns the function suffix for the Testing Library method name
     fn function_suffix(&self) -> &str {
         match self {
@@ -766,11 +1589,12 @@ impl By {
             By::AltText(_, _) => "AltText",
             By::Title(_, _) => "Title",
             By::TestId(_, _) => "TestId",
+            By::Custom(name, _, _) => name,
         }
     }
 
     /// Returns the selector value (text, role, etc.)
-    fn value(&self) -> &str {
+    fn value(&self) -> &TextMatch {
         match self {
             By::Role(value, _) => value,
             By::Text(value, _) => value,
@@ -780,6 +1604,7 @@ impl By {
             By::AltText(value, _) => value,
             By::Title(value, _) => value,
             By::TestId(value, _) => value,
+            By::Custom(_, value, _) => value,
         }
     }
 
@@ -794,17 +1619,19 @@ impl By {
             By::AltText(_, options) => options,
             By::Title(_, options) => options,
             By::TestId(_, options) => options,
+            By::Custom(_, _, options) => options,
         }
     }
 
-    /// Returns the serialized options JSON string if any
-    fn options_json(&self) -> Result<Option<String>, WebDriverError> {
+    /// Returns the serialized options, an empty object if there are none
+    fn options_json(&self) -> TestingLibraryResult<Value> {
         match self.options() {
-            Some(options) => options
-                .to_json_string()
-                .map(Some)
-                .map_err(|e| WebDriverError::Json(format!("Failed to serialize options: {e}"))),
-            None => Ok(None),
+            Some(options) => options.to_json_value().map_err(|e| {
+                TestingLibraryError::ScriptSerialization(format!(
+                    "Failed to serialize options: {e}"
+                ))
+            }),
+            None => Ok(Value::Object(Default::default())),
         }
     }
 }
@@ -813,69 +1640,29 @@ impl By {
 #[derive(Debug, Clone)]
 pub enum By {
     /// Query by element role
-    Role(String, Option<Options>),
+    Role(TextMatch, Option<Options>),
     /// Query by text content
-    Text(String, Option<Options>),
+    Text(TextMatch, Option<Options>),
     /// Query by label text
-    LabelText(String, Option<Options>),
+    LabelText(TextMatch, Option<Options>),
     /// Query by placeholder text
-    PlaceholderText(String, Option<Options>),
+    PlaceholderText(TextMatch, Option<Options>),
     /// Query by display value
-    DisplayValue(String, Option<Options>),
+    DisplayValue(TextMatch, Option<Options>),
     /// Query by alt text
-    AltText(String, Option<Options>),
+    AltText(TextMatch, Option<Options>),
     /// Query by title
-    Title(String, Option<Options>),
+    Title(TextMatch, Option<Options>),
     /// Query by test ID
-    TestId(String, Option<Options>),
+    TestId(TextMatch, Option<Options>),
+    /// Query registered with `Screen::register_query`, by name
+    Custom(String, TextMatch, Option<Options>),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_is_regex_pattern() {
-        // Valid regex patterns
-        assert!(QueryExecutor::is_regex_pattern("/hello/"));
-        assert!(QueryExecutor::is_regex_pattern("/hello/i"));
-        assert!(QueryExecutor::is_regex_pattern("/^submit.*$/i"));
-        assert!(QueryExecutor::is_regex_pattern("/Hello W?oRlD/i"));
-
-        // Invalid regex patterns (not regex)
-        assert!(!QueryExecutor::is_regex_pattern("hello"));
-        assert!(!QueryExecutor::is_regex_pattern("hello/"));
-        assert!(!QueryExecutor::is_regex_pattern("/hello"));
-        assert!(!QueryExecutor::is_regex_pattern("/"));
-        assert!(!QueryExecutor::is_regex_pattern(""));
-        assert!(!QueryExecutor::is_regex_pattern("regular text"));
-    }
-
-    #[test]
-    fn test_format_query_value() {
-        // Regular strings should be quoted
-        assert_eq!(QueryExecutor::format_query_value("hello"), "'hello'");
-        assert_eq!(
-            QueryExecutor::format_query_value("Hello World"),
-            "'Hello World'"
-        );
-        assert_eq!(QueryExecutor::format_query_value("Submit"), "'Submit'");
-
-        // Regex patterns should use marker system
-        assert_eq!(
-            QueryExecutor::format_query_value("/hello/"),
-            "\"__RAW_JS__/hello/\""
-        );
-        assert_eq!(
-            QueryExecutor::format_query_value("/hello/i"),
-            "\"__RAW_JS__/hello/i\""
-        );
-        assert_eq!(
-            QueryExecutor::format_query_value("/^submit.*$/i"),
-            "\"__RAW_JS__/^submit.*$/i\""
-        );
-    }
-
     #[test]
     fn test_regex_functionality_examples() {
         // Test that our TextMatch From implementation works correctly
@@ -893,22 +1680,37 @@ mod tests {
     }
 
     #[test]
-    fn test_process_raw_javascript_markers() {
-        // Test the marker processing function works correctly
-        let json_with_markers =
-            r#"return window.__TL__.getByText(document, "__RAW_JS__/Hello World/i");"#;
-        let processed = process_raw_javascript_markers(json_with_markers);
+    fn test_options_json() {
+        let selector: By = By::text("Don't save").into();
+        assert_eq!(selector.options_json().unwrap(), serde_json::json!({}));
+
+        let selector: By = By::role("button").name("/it's \\ saved/i").into();
         assert_eq!(
-            processed,
-            r#"return window.__TL__.getByText(document, /Hello World/i);"#
+            selector.options_json().unwrap(),
+            serde_json::json!({"name": {"__tlRegex": {"source": "it's \\ saved", "flags": "i"}}})
         );
+    }
 
-        // Test with regular strings (should remain unchanged)
-        let json_no_markers = r#"return window.__TL__.getByText(document, 'Hello World');"#;
-        let processed_no_markers = process_raw_javascript_markers(json_no_markers);
+    #[test]
+    fn test_role_constructors() {
+        let selector: By = By::heading(2).name("Settings").into();
+        assert_eq!(selector.value().text_value(), "heading");
         assert_eq!(
-            processed_no_markers,
-            r#"return window.__TL__.getByText(document, 'Hello World');"#
+            selector.options_json().unwrap(),
+            serde_json::json!({"name": "Settings", "level": 2})
         );
+
+        let selector: By = By::role(AriaRole::TextBox).into();
+        assert_eq!(selector.value().text_value(), "textbox");
+
+        let selector: By = By::role("doc-abstract").into();
+        assert_eq!(selector.value().text_value(), "doc-abstract");
+    }
+
+    #[test]
+    fn test_wrap_script() {
+        let script = QueryExecutor::wrap_script("return arguments[0];");
+        assert!(script.starts_with("if (!window.__TL__) return 'Testing Library not found';"));
+        assert!(script.contains("(function () { return arguments[0]; })"));
     }
 }
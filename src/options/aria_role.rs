@@ -0,0 +1,189 @@
+use crate::options::common::TextMatch;
+use std::fmt;
+
+/// Declare the `AriaRole` enum along with the conversions to and from the role names
+macro_rules! aria_roles {
+    ($($variant:ident => $name:literal,)*) => {
+        /// WAI-ARIA 1.2 roles, `Custom` covers any other role like the DPUB-ARIA ones
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum AriaRole {
+            $(
+                #[doc = concat!("The `", $name, "` role")]
+                $variant,
+            )*
+            /// Any role not listed above
+            Custom(String),
+        }
+
+        impl AriaRole {
+            /// All the WAI-ARIA 1.2 roles
+            pub const ALL: &'static [AriaRole] = &[$(AriaRole::$variant,)*];
+
+            /// Name of the role as used in the `role` attribute
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(AriaRole::$variant => $name,)*
+                    AriaRole::Custom(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for AriaRole {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($name => AriaRole::$variant,)*
+                    name => AriaRole::Custom(name.to_string()),
+                }
+            }
+        }
+    };
+}
+
+aria_roles! {
+    Alert => "alert",
+    AlertDialog => "alertdialog",
+    Application => "application",
+    Article => "article",
+    Banner => "banner",
+    Blockquote => "blockquote",
+    Button => "button",
+    Caption => "caption",
+    Cell => "cell",
+    Checkbox => "checkbox",
+    Code => "code",
+    ColumnHeader => "columnheader",
+    ComboBox => "combobox",
+    Complementary => "complementary",
+    ContentInfo => "contentinfo",
+    Definition => "definition",
+    Deletion => "deletion",
+    Dialog => "dialog",
+    Directory => "directory",
+    Document => "document",
+    Emphasis => "emphasis",
+    Feed => "feed",
+    Figure => "figure",
+    Form => "form",
+    Generic => "generic",
+    Grid => "grid",
+    GridCell => "gridcell",
+    Group => "group",
+    Heading => "heading",
+    Img => "img",
+    Insertion => "insertion",
+    Link => "link",
+    List => "list",
+    ListBox => "listbox",
+    ListItem => "listitem",
+    Log => "log",
+    Main => "main",
+    Marquee => "marquee",
+    Math => "math",
+    Menu => "menu",
+    MenuBar => "menubar",
+    MenuItem => "menuitem",
+    MenuItemCheckbox => "menuitemcheckbox",
+    MenuItemRadio => "menuitemradio",
+    Meter => "meter",
+    Navigation => "navigation",
+    None => "none",
+    Note => "note",
+    Option => "option",
+    Paragraph => "paragraph",
+    Presentation => "presentation",
+    ProgressBar => "progressbar",
+    Radio => "radio",
+    RadioGroup => "radiogroup",
+    Region => "region",
+    Row => "row",
+    RowGroup => "rowgroup",
+    RowHeader => "rowheader",
+    ScrollBar => "scrollbar",
+    Search => "search",
+    SearchBox => "searchbox",
+    Separator => "separator",
+    Slider => "slider",
+    SpinButton => "spinbutton",
+    Status => "status",
+    Strong => "strong",
+    Subscript => "subscript",
+    Superscript => "superscript",
+    Switch => "switch",
+    Tab => "tab",
+    Table => "table",
+    TabList => "tablist",
+    TabPanel => "tabpanel",
+    Term => "term",
+    TextBox => "textbox",
+    Time => "time",
+    Timer => "timer",
+    Toolbar => "toolbar",
+    Tooltip => "tooltip",
+    Tree => "tree",
+    TreeGrid => "treegrid",
+    TreeItem => "treeitem",
+}
+
+impl From<String> for AriaRole {
+    fn from(name: String) -> Self {
+        AriaRole::from(name.as_str())
+    }
+}
+
+impl From<&String> for AriaRole {
+    fn from(name: &String) -> Self {
+        AriaRole::from(name.as_str())
+    }
+}
+
+impl From<AriaRole> for TextMatch {
+    fn from(role: AriaRole) -> Self {
+        match role {
+            // Custom roles keep the regex detection of plain strings
+            AriaRole::Custom(name) => TextMatch::from(name),
+            role => TextMatch::literal(role.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for AriaRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aria_role_names() {
+        assert_eq!(AriaRole::Button.as_str(), "button");
+        assert_eq!(AriaRole::TextBox.as_str(), "textbox");
+        assert_eq!(AriaRole::MenuItemCheckbox.to_string(), "menuitemcheckbox");
+        assert_eq!(
+            AriaRole::Custom("doc-abstract".to_string()).as_str(),
+            "doc-abstract"
+        );
+    }
+
+    #[test]
+    fn test_aria_role_from_str() {
+        assert_eq!(AriaRole::from("heading"), AriaRole::Heading);
+        assert_eq!(
+            AriaRole::from("buton"),
+            AriaRole::Custom("buton".to_string())
+        );
+
+        for role in AriaRole::ALL {
+            assert_eq!(&AriaRole::from(role.as_str()), role);
+        }
+    }
+
+    #[test]
+    fn test_aria_role_into_text_match() {
+        assert_eq!(TextMatch::from(AriaRole::Button).text_value(), "button");
+        assert!(TextMatch::from(AriaRole::Button).is_string());
+        assert!(TextMatch::from(AriaRole::Custom("/button|link/".to_string())).is_regex());
+    }
+}
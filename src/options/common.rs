@@ -1,5 +1,7 @@
+use crate::error::{TestingLibraryError, TestingLibraryResult};
+use crate::options::js_regex;
 use regex;
-use serde::{Serialize, Serializer};
+use serde::{ser::SerializeMap, Serialize, Serializer};
 use serde_json::Value;
 
 /// Common trait for all testing-library option types.
@@ -17,9 +19,7 @@ pub trait TestingLibraryOptions: Serialize + Default {
 
     /// Serialize this options struct to a JSON string.
     fn to_json_string(&self) -> Result<String, serde_json::Error> {
-        let json = serde_json::to_string(self)?;
-        // Post-process to convert marked raw JavaScript values
-        Ok(process_raw_javascript_markers(&json))
+        serde_json::to_string(self)
     }
 
     /// Serialize this options struct to a JSON value.
@@ -28,31 +28,177 @@ pub trait TestingLibraryOptions: Serialize + Default {
     }
 }
 
-/// A wrapper type that indicates a value should be serialized as raw JavaScript
+/// Script defining `revive`, which rebuilds in the page the regexes, function matchers and
+/// normalizers serialized as `{"__tlRegex": ..}`, `{"__tlFunction": ..}` and `{"__tlNormalizer": ..}`.
+/// Invalid regexes throw a `SyntaxError` starting with "Invalid regular expression" in every browser.
+pub(crate) const REVIVE_SCRIPT: &str = r#"
+    const regex = ({ source, flags }) => {
+        try {
+            return new RegExp(source, flags);
+        } catch (error) {
+            if (error.message.startsWith("Invalid regular expression")) throw error;
+            throw new SyntaxError(`Invalid regular expression: /${source}/${flags}: ${error.message}`);
+        }
+    };
+    const revive = (input) => {
+        if (Array.isArray(input)) return input.map(revive);
+        if (input === null || typeof input !== "object") return input;
+        if (input.__tlRegex) return regex(input.__tlRegex);
+        if (input.__tlNormalizer) {
+            const { body, args } = input.__tlNormalizer;
+            const normalizer = new Function("text", "args", body);
+            return (text) => normalizer(text, args);
+        }
+        if (input.__tlFunction) {
+            const { body, args } = input.__tlFunction;
+            const matcher = new Function("content", "element", "args", body);
+            return (content, element) => matcher(content, element, args);
+        }
+        return Object.fromEntries(Object.entries(input).map(([key, item]) => [key, revive(item)]));
+    };
+"#;
+
+/// Serialized form of a regex, rebuilt with `new RegExp(source, flags)` in the page
+#[derive(Debug, Clone, Serialize)]
+struct RegexDescriptor<'a> {
+    source: &'a str,
+    flags: &'a str,
+}
+
+/// JavaScript function matcher evaluated in the page, like the
+/// `(content, element) => boolean` functions accepted by the JavaScript Testing Library
+///
+/// The body is run with `content` (the text or accessible name being matched),
+/// `element` (the candidate element) and `args` (the JSON arguments) in scope.
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionMatcher {
+    body: String,
+    args: Value,
+}
+
+impl FunctionMatcher {
+    /// Create a matcher from a JavaScript function body returning a boolean,
+    /// e.g. `return element.tagName === "SPAN" && content.startsWith("Hello");`
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            args: Value::Null,
+        }
+    }
+
+    /// Create a matcher from a JavaScript function body and JSON arguments available as `args`
+    pub fn with_args(body: impl Into<String>, args: impl Into<Value>) -> Self {
+        Self {
+            body: body.into(),
+            args: args.into(),
+        }
+    }
+
+    /// Match the deepest element whose normalized `textContent` equals the text,
+    /// useful when the text is broken up by multiple elements
+    pub fn text_content_equals(text: impl Into<String>) -> Self {
+        Self::deepest_text_content(text, "text === args")
+    }
+
+    /// Match the deepest element whose normalized `textContent` contains the text
+    pub fn text_content_contains(text: impl Into<String>) -> Self {
+        Self::deepest_text_content(text, "text.includes(args)")
+    }
+
+    fn deepest_text_content(text: impl Into<String>, predicate: &str) -> Self {
+        Self::with_args(
+            format!(
+                "const matches = (node) => {{ \
+                     const text = (node.textContent || \"\").replace(/\\s+/g, \" \").trim(); \
+                     return {predicate}; \
+                 }}; \
+                 return element !== null && matches(element) \
+                     && Array.from(element.children).every((child) => !matches(child));"
+            ),
+            Value::String(text.into()),
+        )
+    }
+
+    /// Get the JavaScript function body
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// JavaScript text normalizer evaluated in the page, like the `normalizer` option
+/// of the JavaScript Testing Library
+///
+/// The body is run with `text` (the text to normalize) and `args` (the JSON arguments)
+/// in scope and must return the normalized string.
 #[derive(Debug, Clone)]
-pub struct RawJavaScript(pub String);
+pub struct Normalizer {
+    body: String,
+    args: Value,
+}
+
+impl Normalizer {
+    /// Create a normalizer from a JavaScript function body returning a string,
+    /// e.g. `return text.replace(/\u00a0/g, " ").trim();`
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            args: Value::Null,
+        }
+    }
+
+    /// Create a normalizer from a JavaScript function body and JSON arguments available as `args`
+    pub fn with_args(body: impl Into<String>, args: impl Into<Value>) -> Self {
+        Self {
+            body: body.into(),
+            args: args.into(),
+        }
+    }
+
+    /// Get the JavaScript function body
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
 
-impl Serialize for RawJavaScript {
+impl Serialize for Normalizer {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // Mark this as a special regex value that needs post-processing
-        // We'll use a special marker that we can detect and replace later
-        let marked_value = format!("__RAW_JS__{}", self.0);
-        marked_value.serialize(serializer)
+        // Wrapped in a marker object so the page can rebuild the function
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            "__tlNormalizer",
+            &FunctionDescriptor {
+                body: &self.body,
+                args: &self.args,
+            },
+        )?;
+        map.end()
     }
 }
 
+/// Serialized form of a JavaScript function, rebuilt with `new Function` in the page
+#[derive(Debug, Clone, Serialize)]
+struct FunctionDescriptor<'a> {
+    body: &'a str,
+    args: &'a Value,
+}
+
 /// Represents text matching options for Testing Library queries
-/// Supports both string and regex patterns like the JavaScript Testing Library
+/// Supports string, regex and function patterns like the JavaScript Testing Library
 /// Exact vs substring behavior is controlled by the `exact` option on queries
+///
+/// Converting from a `&str` treats strings starting and ending with '/' as regexes,
+/// use [`TextMatch::literal`] or [`TextMatch::regex`] to be explicit.
 #[derive(Debug, Clone)]
 pub enum TextMatch {
     /// String match (exact vs substring controlled by query options)
     String(String),
     /// Regular expression match
     Regex(String),
+    /// JavaScript function match
+    Function(Box<FunctionMatcher>),
 }
 
 impl Serialize for TextMatch {
@@ -63,14 +209,40 @@ impl Serialize for TextMatch {
         match self {
             TextMatch::String(s) => s.serialize(serializer),
             TextMatch::Regex(pattern) => {
-                // Use RawJavaScript wrapper to indicate this should be raw JS
-                RawJavaScript(pattern.clone()).serialize(serializer)
+                // Wrapped in a marker object so the page can tell it apart from option objects
+                let (source, flags) = split_regex_literal(pattern);
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("__tlRegex", &RegexDescriptor { source, flags })?;
+                map.end()
+            }
+            TextMatch::Function(function) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("__tlFunction", function)?;
+                map.end()
             }
         }
     }
 }
 
 impl TextMatch {
+    /// Create a string match that is never interpreted as a regex,
+    /// even if it starts and ends with '/'
+    pub fn literal(text: impl Into<String>) -> Self {
+        TextMatch::String(text.into())
+    }
+
+    /// Create a regex match from a Rust regex, translated to a JavaScript regex.
+    /// Fails if the regex uses a construct JavaScript does not support.
+    pub fn regex(regex: &regex::Regex) -> TestingLibraryResult<Self> {
+        let (source, flags) = js_regex::translate(regex.as_str()).map_err(|reason| {
+            TestingLibraryError::UnsupportedRegex {
+                pattern: regex.as_str().to_string(),
+                reason,
+            }
+        })?;
+        Ok(TextMatch::Regex(format!("/{source}/{flags}")))
+    }
+
     /// Validate that the regex pattern is properly formatted
     pub fn validate_regex(&self) -> Result<(), String> {
         match self {
@@ -102,11 +274,19 @@ impl TextMatch {
         }
     }
 
-    /// Get the text value for string matches or pattern for regex matches
+    /// Create a function match from a JavaScript function body,
+    /// see [`FunctionMatcher::new`]
+    pub fn function(body: impl Into<String>) -> Self {
+        TextMatch::Function(Box::new(FunctionMatcher::new(body)))
+    }
+
+    /// Get the text value for string matches, pattern for regex matches
+    /// or function body for function matches
     pub fn text_value(&self) -> &str {
         match self {
             TextMatch::String(text) => text,
             TextMatch::Regex(pattern) => pattern,
+            TextMatch::Function(function) => function.body(),
         }
     }
 
@@ -119,6 +299,11 @@ impl TextMatch {
     pub fn is_regex(&self) -> bool {
         matches!(self, TextMatch::Regex(_))
     }
+
+    /// Check if this is a function match
+    pub fn is_function(&self) -> bool {
+        matches!(self, TextMatch::Function(_))
+    }
 }
 
 impl From<&str> for TextMatch {
@@ -140,10 +325,105 @@ impl From<String> for TextMatch {
     }
 }
 
-/// Convert marked raw JavaScript values to actual raw JavaScript
-pub fn process_raw_javascript_markers(json: &str) -> String {
-    // Replace "__RAW_JS__/pattern/" with /pattern/ (remove quotes and marker)
-    use regex::Regex;
-    let re = Regex::new(r#""__RAW_JS__([^"]+)""#).unwrap();
-    re.replace_all(json, "$1").to_string()
+impl From<&String> for TextMatch {
+    fn from(text: &String) -> Self {
+        TextMatch::from(text.as_str())
+    }
+}
+
+impl From<FunctionMatcher> for TextMatch {
+    fn from(function: FunctionMatcher) -> Self {
+        TextMatch::Function(Box::new(function))
+    }
+}
+
+impl TryFrom<&regex::Regex> for TextMatch {
+    type Error = TestingLibraryError;
+
+    fn try_from(regex: &regex::Regex) -> Result<Self, Self::Error> {
+        TextMatch::regex(regex)
+    }
+}
+
+impl TryFrom<regex::Regex> for TextMatch {
+    type Error = TestingLibraryError;
+
+    fn try_from(regex: regex::Regex) -> Result<Self, Self::Error> {
+        TextMatch::regex(&regex)
+    }
+}
+
+/// Split a `/pattern/flags` regex literal into its source and flags
+fn split_regex_literal(pattern: &str) -> (&str, &str) {
+    match pattern.rfind('/') {
+        Some(last_slash) if pattern.starts_with('/') && last_slash > 0 => {
+            (&pattern[1..last_slash], &pattern[last_slash + 1..])
+        }
+        _ => (pattern, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_regex_literal() {
+        assert_eq!(split_regex_literal("/hello/"), ("hello", ""));
+        assert_eq!(split_regex_literal("/hello/gi"), ("hello", "gi"));
+        assert_eq!(split_regex_literal("/a/b/i"), ("a/b", "i"));
+    }
+
+    #[test]
+    fn test_text_match_serialization() {
+        let string_match = serde_json::to_value(TextMatch::from("Don't 'save' \\ now")).unwrap();
+        assert_eq!(string_match, "Don't 'save' \\ now");
+
+        let regex_match = serde_json::to_value(TextMatch::from("/^it's.*$/i")).unwrap();
+        assert_eq!(
+            regex_match,
+            serde_json::json!({"__tlRegex": {"source": "^it's.*$", "flags": "i"}})
+        );
+    }
+
+    #[test]
+    fn test_text_match_literal() {
+        let literal = TextMatch::literal("/api/");
+        assert!(literal.is_string());
+        assert_eq!(serde_json::to_value(literal).unwrap(), "/api/");
+    }
+
+    #[test]
+    fn test_text_match_function_serialization() {
+        let function = TextMatch::function("return content.startsWith('Hello');");
+        assert!(function.is_function());
+        assert_eq!(
+            serde_json::to_value(function).unwrap(),
+            serde_json::json!({"__tlFunction": {"body": "return content.startsWith('Hello');", "args": null}})
+        );
+
+        let function = TextMatch::from(FunctionMatcher::text_content_equals("Hello world"));
+        let json = serde_json::to_value(function).unwrap();
+        assert_eq!(json["__tlFunction"]["args"], "Hello world");
+        assert!(json["__tlFunction"]["body"]
+            .as_str()
+            .unwrap()
+            .contains("text === args"));
+    }
+
+    #[test]
+    fn test_text_match_from_rust_regex() {
+        let regex = regex::Regex::new(r"(?i)^save (?P<what>[a-z]+)$").unwrap();
+        let text_match = TextMatch::try_from(&regex).unwrap();
+        assert_eq!(
+            serde_json::to_value(text_match).unwrap(),
+            serde_json::json!({"__tlRegex": {"source": "^save (?<what>[a-z]+)$", "flags": "iu"}})
+        );
+
+        let unsupported = regex::Regex::new(r"(?x) save \s+ ").unwrap();
+        assert!(matches!(
+            TextMatch::regex(&unsupported),
+            Err(TestingLibraryError::UnsupportedRegex { .. })
+        ));
+    }
 }
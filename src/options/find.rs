@@ -0,0 +1,163 @@
+use crate::options::common::TestingLibraryOptions;
+use serde::{Serialize, Serializer};
+use std::time::Duration;
+
+/// Options for the MutationObserver used by find queries to know when to check again
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutationObserverOptions {
+    /// Observe the whole subtree of the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtree: Option<bool>,
+    /// Observe children being added or removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_list: Option<bool>,
+    /// Observe attribute changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<bool>,
+    /// Observe text content changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character_data: Option<bool>,
+    /// Only observe changes of these attributes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_filter: Option<Vec<String>>,
+}
+
+impl MutationObserverOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the subtree option
+    pub fn subtree(mut self, subtree: bool) -> Self {
+        self.subtree = Some(subtree);
+        self
+    }
+
+    /// Set the child_list option
+    pub fn child_list(mut self, child_list: bool) -> Self {
+        self.child_list = Some(child_list);
+        self
+    }
+
+    /// Set the attributes option
+    pub fn attributes(mut self, attributes: bool) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Set the character_data option
+    pub fn character_data(mut self, character_data: bool) -> Self {
+        self.character_data = Some(character_data);
+        self
+    }
+
+    /// Set the attribute_filter option
+    pub fn attribute_filter<I, S>(mut self, attribute_filter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.attribute_filter = Some(attribute_filter.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Wait options for find queries, overriding `async_util_timeout` for a single call
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitOptions {
+    /// How long to wait for the element, defaults to `async_util_timeout`
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_millis"
+    )]
+    pub timeout: Option<Duration>,
+    /// How long to wait between two checks, defaults to 50ms
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_millis"
+    )]
+    pub interval: Option<Duration>,
+    /// Which DOM changes trigger a new check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutation_observer_options: Option<MutationObserverOptions>,
+}
+
+impl WaitOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout option
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the interval option
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Set the mutation_observer_options option
+    pub fn mutation_observer_options(
+        mut self,
+        mutation_observer_options: MutationObserverOptions,
+    ) -> Self {
+        self.mutation_observer_options = Some(mutation_observer_options);
+        self
+    }
+}
+
+impl TestingLibraryOptions for WaitOptions {}
+
+/// Durations are given to Testing Library in milliseconds
+fn serialize_millis<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match duration {
+        Some(duration) => {
+            serializer.serialize_u64(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wait_options_empty_serialization() {
+        let options = WaitOptions::new();
+        assert_eq!(options.to_json_string().unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_wait_options_serialization() {
+        let options = WaitOptions::new()
+            .timeout(Duration::from_secs(30))
+            .interval(Duration::from_millis(250))
+            .mutation_observer_options(
+                MutationObserverOptions::new()
+                    .subtree(true)
+                    .child_list(true)
+                    .attribute_filter(["aria-busy"]),
+            );
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["timeout"], 30000);
+        assert_eq!(json_value["interval"], 250);
+        assert_eq!(json_value["mutationObserverOptions"]["subtree"], true);
+        assert_eq!(json_value["mutationObserverOptions"]["childList"], true);
+        assert_eq!(
+            json_value["mutationObserverOptions"]["attributeFilter"],
+            serde_json::json!(["aria-busy"])
+        );
+    }
+}
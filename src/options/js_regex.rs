@@ -0,0 +1,436 @@
+//! Translation of Rust regexes into JavaScript regexes
+//!
+//! Rust and JavaScript regex syntaxes mostly overlap. The translation rewrites the
+//! few constructs that are spelled differently and rejects the ones JavaScript has
+//! no equivalent for. The `u` flag is always set for `\p{..}` classes and `\u{..}` escapes.
+//! The Perl classes (`\d`, `\w`, `\b`...), which only cover ASCII in JavaScript, are rewritten
+//! with the Unicode properties Rust uses for them, and Rust's Unicode classes are expanded
+//! into the code point ranges they stand for.
+
+use regex_syntax::hir::{Class, HirKind};
+
+/// Flags that Rust allows to set at the start of a pattern and JavaScript supports
+const SUPPORTED_FLAGS: [char; 3] = ['i', 'm', 's'];
+
+/// Unicode properties of Rust's `\d`
+const DIGIT: &str = r"\p{Nd}";
+
+/// Unicode properties of Rust's `\w`, without the surrounding brackets
+const WORD: &str = r"\p{Alphabetic}\p{M}\p{Nd}\p{Pc}\p{Join_Control}";
+
+/// Translate a Rust regex pattern into a JavaScript `(source, flags)` pair
+pub(crate) fn translate(pattern: &str) -> Result<(String, String), String> {
+    let (mut flags, rest) = leading_flags(pattern)?;
+    let multi_line = flags.contains('m');
+    flags.push('u');
+
+    let mut source = String::with_capacity(rest.len());
+    let mut chars = rest.chars().peekable();
+    let mut in_class = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| "pattern ends with a lone '\\'".to_string())?;
+                translate_escape(escaped, &mut chars, in_class, multi_line, &mut source)?;
+            }
+            '[' if in_class => {
+                if chars.peek() == Some(&':') {
+                    return Err(
+                        "ASCII character classes like [[:alpha:]] are not supported in JavaScript"
+                            .to_string(),
+                    );
+                }
+                return Err("nested character classes are not supported in JavaScript".to_string());
+            }
+            '[' => {
+                in_class = true;
+                source.push('[');
+                if chars.peek() == Some(&'^') {
+                    chars.next();
+                    source.push('^');
+                }
+                // A leading ']' is a literal in Rust but closes an empty class in JavaScript
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    source.push_str("\\]");
+                }
+            }
+            ']' if in_class => {
+                in_class = false;
+                source.push(']');
+            }
+            '&' | '-' | '~' if in_class && chars.peek() == Some(&c) => {
+                return Err(format!(
+                    "character class set operation '{c}{c}' is not supported in JavaScript"
+                ));
+            }
+            '{' if !in_class => {
+                // Repetition quantifiers share the JavaScript syntax
+                source.push('{');
+                for c in chars.by_ref() {
+                    source.push(c);
+                    if c == '}' {
+                        break;
+                    }
+                }
+            }
+            ']' | '}' => {
+                // Literal in Rust, rejected by JavaScript unicode mode when unescaped
+                source.push('\\');
+                source.push(c);
+            }
+            '(' if !in_class && chars.peek() == Some(&'?') => {
+                chars.next();
+                translate_group(&mut chars, &mut source)?;
+            }
+            c => source.push(c),
+        }
+    }
+
+    Ok((source, flags))
+}
+
+/// Extract the `(?flags)` group Rust allows at the start of a pattern
+fn leading_flags(pattern: &str) -> Result<(String, &str), String> {
+    let Some(group) = pattern.strip_prefix("(?") else {
+        return Ok((String::new(), pattern));
+    };
+    let Some(end) = group.find(')') else {
+        return Ok((String::new(), pattern));
+    };
+
+    let candidate = &group[..end];
+    if candidate.is_empty()
+        || !candidate
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '-')
+    {
+        return Ok((String::new(), pattern));
+    }
+
+    let mut flags = String::new();
+    for flag in candidate.chars() {
+        match flag {
+            // Rust is Unicode aware by default, the `u` flag is always set
+            'u' => {}
+            flag if SUPPORTED_FLAGS.contains(&flag) => {
+                if !flags.contains(flag) {
+                    flags.push(flag);
+                }
+            }
+            '-' => {
+                return Err("disabling flags with '-' is not supported in JavaScript".to_string())
+            }
+            flag => return Err(format!("flag '{flag}' is not supported in JavaScript")),
+        }
+    }
+
+    Ok((flags, &group[end + 1..]))
+}
+
+/// Translate the content of a `(?...` group, after the `?`
+fn translate_group(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    source: &mut String,
+) -> Result<(), String> {
+    match chars.peek() {
+        Some(':') | Some('=') | Some('!') => {
+            source.push_str("(?");
+            Ok(())
+        }
+        Some('P') => {
+            chars.next();
+            if chars.peek() != Some(&'<') {
+                return Err("unknown group syntax '(?P'".to_string());
+            }
+            source.push_str("(?");
+            Ok(())
+        }
+        Some('<') => {
+            source.push_str("(?");
+            Ok(())
+        }
+        _ => Err(
+            "inline flags are only supported at the start of the pattern in JavaScript".to_string(),
+        ),
+    }
+}
+
+/// Translate an escape sequence, after the `\`
+fn translate_escape(
+    escaped: char,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    in_class: bool,
+    multi_line: bool,
+    source: &mut String,
+) -> Result<(), String> {
+    match escaped {
+        'A' | 'z' if multi_line => Err(format!(
+            "'\\{escaped}' cannot be combined with the 'm' flag in JavaScript"
+        )),
+        'A' => {
+            source.push('^');
+            Ok(())
+        }
+        'z' => {
+            source.push('$');
+            Ok(())
+        }
+        'a' => {
+            source.push_str("\\x07");
+            Ok(())
+        }
+        'p' | 'P' => translate_property(escaped, chars, in_class, source),
+        'x' => translate_hex(escaped, chars, 2, source),
+        'u' => translate_hex(escaped, chars, 4, source),
+        'U' => translate_hex(escaped, chars, 8, source),
+        'b' if chars.peek() == Some(&'{') => Err(
+            "word boundary assertions like \\b{start} are not supported in JavaScript".to_string(),
+        ),
+        'd' => {
+            source.push_str(DIGIT);
+            Ok(())
+        }
+        'D' => {
+            source.push_str(r"\P{Nd}");
+            Ok(())
+        }
+        'w' if in_class => {
+            source.push_str(WORD);
+            Ok(())
+        }
+        'w' => {
+            source.push_str(&format!("[{WORD}]"));
+            Ok(())
+        }
+        'W' if in_class => {
+            Err("'\\W' inside a character class is not supported in JavaScript".to_string())
+        }
+        'W' => {
+            source.push_str(&format!("[^{WORD}]"));
+            Ok(())
+        }
+        'b' | 'B' if in_class => Err(format!(
+            "'\\{escaped}' is not allowed inside a character class"
+        )),
+        'b' => {
+            source.push_str(&format!(
+                "(?:(?<=[{WORD}])(?![{WORD}])|(?<![{WORD}])(?=[{WORD}]))"
+            ));
+            Ok(())
+        }
+        'B' => {
+            source.push_str(&format!(
+                "(?:(?<=[{WORD}])(?=[{WORD}])|(?<![{WORD}])(?![{WORD}]))"
+            ));
+            Ok(())
+        }
+        '<' | '>' => Err(format!(
+            "word boundary assertion '\\{escaped}' is not supported in JavaScript"
+        )),
+        '-' if in_class => {
+            source.push_str("\\-");
+            Ok(())
+        }
+        escaped if escaped.is_ascii_alphanumeric() || is_syntax_character(escaped) => {
+            source.push('\\');
+            source.push(escaped);
+            Ok(())
+        }
+        // Rust allows escaping any punctuation, JavaScript unicode mode only its syntax characters
+        escaped => {
+            source.push(escaped);
+            Ok(())
+        }
+    }
+}
+
+/// Characters that JavaScript unicode mode allows to escape
+fn is_syntax_character(c: char) -> bool {
+    matches!(
+        c,
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '/'
+    )
+}
+
+/// Write a Unicode class (`\pL`, `\p{Greek}`, `\P{sc=Latin}`...) as the code point ranges
+/// Rust resolves it to. JavaScript spells some property names differently (`\p{Script=Greek}`)
+/// and does not accept Rust's loose spellings like `\p{greek}` or `\p{Lu }`.
+fn translate_property(
+    escaped: char,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    in_class: bool,
+    source: &mut String,
+) -> Result<(), String> {
+    let mut class = format!("\\{escaped}");
+    if chars.peek() == Some(&'{') {
+        for c in chars.by_ref() {
+            class.push(c);
+            if c == '}' {
+                break;
+            }
+        }
+    } else {
+        class.push(
+            chars
+                .next()
+                .ok_or_else(|| format!("'\\{escaped}' is missing its class name"))?,
+        );
+    }
+
+    let hir = regex_syntax::Parser::new()
+        .parse(&class)
+        .map_err(|error| error.to_string())?;
+    let HirKind::Class(Class::Unicode(ranges)) = hir.kind() else {
+        return Err(format!("'{class}' is not a Unicode class"));
+    };
+
+    if !in_class {
+        source.push('[');
+    }
+    for range in ranges.iter() {
+        push_code_point(range.start(), source);
+        if range.end() != range.start() {
+            source.push('-');
+            push_code_point(range.end(), source);
+        }
+    }
+    if !in_class {
+        source.push(']');
+    }
+    Ok(())
+}
+
+/// Write a `\x`, `\u` or `\U` escape, with `digits` hex digits or braces, as a JavaScript `\u{..}`
+fn translate_hex(
+    escaped: char,
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    digits: usize,
+    source: &mut String,
+) -> Result<(), String> {
+    let hex: String = if chars.peek() == Some(&'{') {
+        chars.next();
+        chars.by_ref().take_while(|&c| c != '}').collect()
+    } else {
+        chars.by_ref().take(digits).collect()
+    };
+    let code_point = u32::from_str_radix(hex.trim(), 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("'\\{escaped}{hex}' is not a valid code point"))?;
+    push_code_point(code_point, source);
+    Ok(())
+}
+
+/// Write a code point as a JavaScript `\u{..}` escape
+fn push_code_point(c: char, source: &mut String) {
+    source.push_str(&format!("\\u{{{:X}}}", c as u32));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn js(pattern: &str) -> (String, String) {
+        translate(pattern).unwrap()
+    }
+
+    #[test]
+    fn test_translate_plain_pattern() {
+        assert_eq!(js("^Save.*$"), ("^Save.*$".to_string(), "u".to_string()));
+        assert_eq!(
+            js(r"\s+ items?"),
+            (r"\s+ items?".to_string(), "u".to_string())
+        );
+        assert_eq!(js("/api/"), ("/api/".to_string(), "u".to_string()));
+        assert_eq!(
+            js(r"\d{2,4}"),
+            (r"\p{Nd}{2,4}".to_string(), "u".to_string())
+        );
+    }
+
+    #[test]
+    fn test_translate_leading_flags() {
+        assert_eq!(js("(?i)submit"), ("submit".to_string(), "iu".to_string()));
+        assert_eq!(js("(?ims)a.b"), ("a.b".to_string(), "imsu".to_string()));
+        assert_eq!(js("(?:a|b)"), ("(?:a|b)".to_string(), "u".to_string()));
+    }
+
+    #[test]
+    fn test_translate_rewritten_constructs() {
+        assert_eq!(js(r"(?P<name>.+)").0, r"(?<name>.+)");
+        assert_eq!(js(r"\AHello\z").0, "^Hello$");
+        assert_eq!(js(r"\x{1F600}").0, r"\u{1F600}");
+        assert_eq!(js(r"[]a]").0, r"[\]a]");
+        assert_eq!(js(r"a\-b[\-]").0, r"a-b[\-]");
+        assert_eq!(js(r"a]").0, r"a\]");
+    }
+
+    #[test]
+    fn test_translate_hex_escapes() {
+        assert_eq!(js(r"\x41\x{e9}").0, r"\u{41}\u{E9}");
+        assert_eq!(js(r"\u00E9\u{e9}").0, r"\u{E9}\u{E9}");
+        assert_eq!(js(r"\U0001F600\U{1F600}").0, r"\u{1F600}\u{1F600}");
+        assert_eq!(js(r"[\u0041-\u005A]").0, r"[\u{41}-\u{5A}]");
+    }
+
+    #[test]
+    fn test_translate_unicode_classes() {
+        let greek = js(r"\p{Greek}").0;
+        assert!(greek.starts_with(r"[\u{370}-\u{373}\u{375}-\u{377}"));
+        assert!(greek.ends_with(']'));
+        assert_eq!(js(r"\p{greek}").0, greek);
+        assert_eq!(js(r"\p{sc=Greek}").0, greek);
+        assert_eq!(js(r"\p{Script = greek}").0, greek);
+        assert_eq!(js(r"\p{Greek}+").0, format!("{greek}+"));
+        assert_eq!(js(r"\p{Lu }").0, js(r"\p{Uppercase_Letter}").0);
+        assert_eq!(js(r"\pL").0, js(r"\p{Letter}").0);
+        assert_ne!(js(r"\P{Greek}").0, greek);
+        assert_eq!(js(r"\P{Greek}").0, js(r"\p{sc!=Greek}").0);
+        assert_eq!(
+            js(r"[a\p{Greek}]").0,
+            format!("[a{}]", &greek[1..greek.len() - 1])
+        );
+        assert!(translate(r"\p{Klingon}").is_err());
+    }
+
+    #[test]
+    fn test_translate_perl_classes() {
+        let word = r"\p{Alphabetic}\p{M}\p{Nd}\p{Pc}\p{Join_Control}";
+        assert_eq!(js(r"\d\D").0, r"\p{Nd}\P{Nd}");
+        assert_eq!(js(r"^\w+$").0, format!("^[{word}]+$"));
+        assert_eq!(js(r"\W").0, format!("[^{word}]"));
+        assert_eq!(js(r"[\w-]").0, format!("[{word}-]"));
+        assert_eq!(
+            js(r"\bcafé\b").0,
+            format!(
+                "(?:(?<=[{word}])(?![{word}])|(?<![{word}])(?=[{word}]))café\
+                 (?:(?<=[{word}])(?![{word}])|(?<![{word}])(?=[{word}]))"
+            )
+        );
+        assert!(js(r"\B")
+            .0
+            .starts_with(&format!("(?:(?<=[{word}])(?=[{word}])")));
+        assert!(translate(r"[^\W]").is_err());
+
+        // Rust classes are Unicode aware, the translation keeps matching non-ASCII text
+        assert!(regex::Regex::new(r"^\w+$").unwrap().is_match("café"));
+        assert!(regex::Regex::new(r"^\d+$").unwrap().is_match("١٢٣"));
+    }
+
+    #[test]
+    fn test_translate_unsupported_constructs() {
+        assert!(translate("(?x)a b").is_err());
+        assert!(translate("(?U)a+").is_err());
+        assert!(translate("(?-i)a").is_err());
+        assert!(translate("a(?i)b").is_err());
+        assert!(translate("(?i:a)b").is_err());
+        assert!(translate("[[:alpha:]]").is_err());
+        assert!(translate("[a-z&&[^aeiou]]").is_err());
+        assert!(translate(r"\bword\b{end}").is_err());
+        assert!(translate(r"(?m)\Astart").is_err());
+    }
+}
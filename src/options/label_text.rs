@@ -1,4 +1,4 @@
-use crate::options::common::TestingLibraryOptions;
+use crate::options::common::{Normalizer, TestingLibraryOptions};
 use serde::Serialize;
 
 /// Options for label text queries
@@ -11,6 +11,15 @@ pub struct ByLabelTextOptions {
     /// Whether to use exact text matching
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exact: Option<bool>,
+    /// Whether to trim whitespace around the text, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<bool>,
+    /// Whether to collapse inner whitespace into a single space, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse_whitespace: Option<bool>,
+    /// Custom normalizer, cannot be combined with trim and collapse_whitespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<Normalizer>,
 }
 
 impl ByLabelTextOptions {
@@ -30,6 +39,33 @@ impl ByLabelTextOptions {
         self.exact = Some(exact);
         self
     }
+
+    /// Set the trim option
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Check if no option is set
+    pub fn is_empty(&self) -> bool {
+        self.selector.is_none()
+            && self.exact.is_none()
+            && self.trim.is_none()
+            && self.collapse_whitespace.is_none()
+            && self.normalizer.is_none()
+    }
 }
 
 impl TestingLibraryOptions for ByLabelTextOptions {}
@@ -63,6 +99,25 @@ mod tests {
         assert_eq!(json_value["exact"], true);
     }
 
+    #[test]
+    fn test_label_text_options_normalization_serialization() {
+        let options = ByLabelTextOptions::new()
+            .trim(false)
+            .collapse_whitespace(true)
+            .normalizer(Normalizer::with_args(
+                "return text.split(args).join(' ');",
+                "\u{00a0}",
+            ));
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["trim"], false);
+        assert_eq!(json_value["collapseWhitespace"], true);
+        assert_eq!(
+            json_value["normalizer"]["__tlNormalizer"]["args"],
+            "\u{00a0}"
+        );
+    }
+
     #[test]
     fn test_label_text_options_json_string() {
         let options = ByLabelTextOptions::new().selector("textarea").exact(true);
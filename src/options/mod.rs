@@ -3,17 +3,28 @@
 //! This module contains all option types for testing-library queries,
 //! organized into a clean hierarchy.
 
+/// Typed WAI-ARIA roles
+pub mod aria_role;
 /// Common trait for all testing library options
 pub mod common;
+/// Wait options for find queries
+pub mod find;
+/// Translation of Rust regexes into JavaScript regexes
+mod js_regex;
 /// Label text query options
 pub mod label_text;
 /// Role-based query options
 pub mod role;
 /// Simple options for basic query types
 pub mod simple;
+/// Text query options
+pub mod text;
 
 // Re-export everything for convenience
-pub use common::{process_raw_javascript_markers, RawJavaScript, TestingLibraryOptions, TextMatch};
+pub use aria_role::AriaRole;
+pub use common::{FunctionMatcher, Normalizer, TestingLibraryOptions, TextMatch};
+pub use find::*;
 pub use label_text::*;
 pub use role::*;
 pub use simple::*;
+pub use text::*;
@@ -1,9 +1,6 @@
 use crate::options::common::{TestingLibraryOptions, TextMatch};
 use serde::{Serialize, Serializer};
 
-#[cfg(test)]
-use crate::options::common::process_raw_javascript_markers;
-
 #[cfg(test)]
 use serde_json::Value;
 
@@ -13,13 +10,13 @@ use serde_json::Value;
 pub struct ValueOptions {
     /// Minimum value (aria-valuemin)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub min: Option<i32>,
+    pub min: Option<f64>,
     /// Maximum value (aria-valuemax)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max: Option<i32>,
+    pub max: Option<f64>,
     /// Current value (aria-valuenow)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub now: Option<i32>,
+    pub now: Option<f64>,
     /// Text representation of value (aria-valuetext)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<TextMatch>,
@@ -46,10 +43,16 @@ pub struct ByRoleOptions {
     pub busy: Option<bool>,
     /// Filter by checked state (aria-checked)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub checked: Option<bool>,
+    pub checked: Option<TriState>,
     /// Filter by pressed state (aria-pressed)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pressed: Option<bool>,
+    pub pressed: Option<TriState>,
+    /// Filter by disabled state (disabled or aria-disabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// Filter by invalid state (aria-invalid)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid: Option<bool>,
     /// Enable/disable query suggestions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suggest: Option<bool>,
@@ -70,6 +73,40 @@ pub struct ByRoleOptions {
     pub value: Option<ValueOptions>,
 }
 
+/// Represents a tri-state ARIA attribute like aria-checked or aria-pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriState {
+    /// aria-checked="false" or aria-pressed="false"
+    False,
+    /// aria-checked="true" or aria-pressed="true"
+    True,
+    /// aria-checked="mixed", aria-pressed="mixed" or an indeterminate checkbox
+    Mixed,
+}
+
+impl Serialize for TriState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TriState::False => false.serialize(serializer),
+            TriState::True => true.serialize(serializer),
+            TriState::Mixed => "mixed".serialize(serializer),
+        }
+    }
+}
+
+impl From<bool> for TriState {
+    fn from(state: bool) -> Self {
+        if state {
+            TriState::True
+        } else {
+            TriState::False
+        }
+    }
+}
+
 /// Represents the current state for aria-current attribute
 #[derive(Debug, Clone)]
 pub enum CurrentState {
@@ -122,18 +159,18 @@ impl ByRoleOptions {
     }
 
     /// Set the name option
-    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/')
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        let name_str = name.into();
-        self.name = Some(TextMatch::from(name_str));
+    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/'),
+    /// or an explicit `TextMatch`
+    pub fn name(mut self, name: impl Into<TextMatch>) -> Self {
+        self.name = Some(name.into());
         self
     }
 
     /// Set the description option
-    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/')
-    pub fn description(mut self, description: impl Into<String>) -> Self {
-        let desc_str = description.into();
-        self.description = Some(TextMatch::from(desc_str));
+    /// Accepts strings and automatically detects regex patterns (strings starting and ending with '/'),
+    /// or an explicit `TextMatch`
+    pub fn description(mut self, description: impl Into<TextMatch>) -> Self {
+        self.description = Some(description.into());
         self
     }
 
@@ -150,14 +187,26 @@ impl ByRoleOptions {
     }
 
     /// Set the checked option
-    pub fn checked(mut self, checked: bool) -> Self {
-        self.checked = Some(checked);
+    pub fn checked(mut self, checked: impl Into<TriState>) -> Self {
+        self.checked = Some(checked.into());
         self
     }
 
     /// Set the pressed option
-    pub fn pressed(mut self, pressed: bool) -> Self {
-        self.pressed = Some(pressed);
+    pub fn pressed(mut self, pressed: impl Into<TriState>) -> Self {
+        self.pressed = Some(pressed.into());
+        self
+    }
+
+    /// Set the disabled option
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Set the invalid option
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = Some(invalid);
         self
     }
 
@@ -233,14 +282,14 @@ mod tests {
     fn test_text_match_regex_serialization() {
         let options = ByRoleOptions::new().name("/^submit.*/");
 
-        // Test the string serialization (which processes markers)
-        let json_string = options.to_json_string().unwrap();
-        assert!(json_string.contains("/^submit.*/"));
-
-        // Note: to_json_value returns the raw marker, which is expected
-        // since it's used internally before marker processing
+        // Regexes are sent as descriptors and rebuilt in the page
         let json_value = options.to_json_value().unwrap();
-        assert_eq!(json_value["name"], "__RAW_JS__/^submit.*/");
+        assert_eq!(json_value["name"]["__tlRegex"]["source"], "^submit.*");
+        assert_eq!(json_value["name"]["__tlRegex"]["flags"], "");
+
+        // The string form is plain JSON
+        let json_string = options.to_json_string().unwrap();
+        assert!(serde_json::from_str::<Value>(&json_string).is_ok());
     }
 
     #[test]
@@ -262,21 +311,43 @@ mod tests {
     #[test]
     fn test_value_options_serialization() {
         let value_opts = ValueOptions {
-            min: Some(0),
-            max: Some(100),
-            now: Some(50),
+            min: Some(0.0),
+            max: Some(100.0),
+            now: Some(50.5),
             text: Some(TextMatch::String("medium".to_string())),
         };
 
         let options = ByRoleOptions::new().value(value_opts);
 
         let json_value = options.to_json_value().unwrap();
-        assert_eq!(json_value["value"]["min"], 0);
-        assert_eq!(json_value["value"]["max"], 100);
-        assert_eq!(json_value["value"]["now"], 50);
+        assert_eq!(json_value["value"]["min"], 0.0);
+        assert_eq!(json_value["value"]["max"], 100.0);
+        assert_eq!(json_value["value"]["now"], 50.5);
         assert_eq!(json_value["value"]["text"], "medium");
     }
 
+    #[test]
+    fn test_tri_state_serialization() {
+        let options = ByRoleOptions::new().checked(TriState::Mixed).pressed(true);
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["checked"], "mixed");
+        assert_eq!(json_value["pressed"], true);
+
+        let options = ByRoleOptions::new().checked(false);
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["checked"], false);
+    }
+
+    #[test]
+    fn test_disabled_invalid_serialization() {
+        let options = ByRoleOptions::new().disabled(true).invalid(false);
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["disabled"], true);
+        assert_eq!(json_value["invalid"], false);
+    }
+
     #[test]
     fn test_query_fallbacks_rename() {
         let options = ByRoleOptions::new().query_fallbacks(true);
@@ -320,10 +391,9 @@ mod tests {
 
         // This would be used in JavaScript like:
         // getByRole('button', {name: /submit|send/, pressed: false, hidden: false, suggest: true})
-
-        // Note: The processed JSON is not valid JSON because regex is unquoted
-        // This is intentional for JavaScript consumption
-        assert!(json_string.contains("/submit|send/"));
+        // once the regex descriptor is rebuilt in the page
+        let parsed: Value = serde_json::from_str(&json_string).unwrap();
+        assert_eq!(parsed["name"]["__tlRegex"]["source"], "submit|send");
         assert!(json_string.contains("\"pressed\":false"));
         assert!(json_string.contains("\"hidden\":false"));
         assert!(json_string.contains("\"suggest\":true"));
@@ -349,30 +419,4 @@ mod tests {
         let string_match = TextMatch::String("test".to_string());
         assert!(string_match.validate_regex().is_ok());
     }
-
-    #[test]
-    fn test_raw_javascript_marker_processing() {
-        // Test the marker processing function
-        let json_with_markers = r#"{"name":"__RAW_JS__/Save.*/","pressed":false}"#;
-        let processed = process_raw_javascript_markers(json_with_markers);
-        assert_eq!(processed, r#"{"name":/Save.*/,"pressed":false}"#);
-
-        // Test with flags
-        let json_with_flags = r#"{"name":"__RAW_JS__/save/i","hidden":true}"#;
-        let processed_flags = process_raw_javascript_markers(json_with_flags);
-        assert_eq!(processed_flags, r#"{"name":/save/i,"hidden":true}"#);
-
-        // Test with multiple markers
-        let json_multiple = r#"{"name":"__RAW_JS__/button/","description":"__RAW_JS__/click.*/i"}"#;
-        let processed_multiple = process_raw_javascript_markers(json_multiple);
-        assert_eq!(
-            processed_multiple,
-            r#"{"name":/button/,"description":/click.*/i}"#
-        );
-
-        // Test with no markers (should remain unchanged)
-        let json_no_markers = r#"{"name":"button","pressed":true}"#;
-        let processed_no_markers = process_raw_javascript_markers(json_no_markers);
-        assert_eq!(processed_no_markers, r#"{"name":"button","pressed":true}"#);
-    }
 }
@@ -1,9 +1,9 @@
-use crate::options::common::TestingLibraryOptions;
+use crate::options::common::{Normalizer, TestingLibraryOptions};
 use serde::Serialize;
 
-/// Simple options struct for testing-library queries that only need exact matching.
+/// Simple options struct for testing-library queries that only need text matching options.
 ///
-/// This struct consolidates the common pattern used by text, alt_text, display_value,
+/// This struct consolidates the common pattern used by alt_text, display_value,
 /// placeholder_text, test_id, and title options.
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,6 +11,15 @@ pub struct SimpleOptions {
     /// Whether to use exact text matching
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exact: Option<bool>,
+    /// Whether to trim whitespace around the text, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<bool>,
+    /// Whether to collapse inner whitespace into a single space, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse_whitespace: Option<bool>,
+    /// Custom normalizer, cannot be combined with trim and collapse_whitespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<Normalizer>,
 }
 
 impl SimpleOptions {
@@ -24,12 +33,37 @@ impl SimpleOptions {
         self.exact = Some(exact);
         self
     }
+
+    /// Set the trim option
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Check if no option is set
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_none()
+            && self.trim.is_none()
+            && self.collapse_whitespace.is_none()
+            && self.normalizer.is_none()
+    }
 }
 
 impl TestingLibraryOptions for SimpleOptions {}
 
 // Type aliases for clarity and API compatibility
-pub type ByTextOptions = SimpleOptions;
 pub type ByAltTextOptions = SimpleOptions;
 pub type ByDisplayValueOptions = SimpleOptions;
 pub type ByPlaceholderTextOptions = SimpleOptions;
@@ -39,6 +73,7 @@ pub type ByTitleOptions = SimpleOptions;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::text::ByTextOptions;
 
     #[test]
     fn test_simple_options_empty_serialization() {
@@ -63,6 +98,27 @@ mod tests {
         assert_eq!(json_value["exact"], false);
     }
 
+    #[test]
+    fn test_simple_options_normalization_serialization() {
+        let options = SimpleOptions::new().trim(false).collapse_whitespace(false);
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["trim"], false);
+        assert_eq!(json_value["collapseWhitespace"], false);
+        assert!(!options.is_empty());
+    }
+
+    #[test]
+    fn test_simple_options_normalizer_serialization() {
+        let options = SimpleOptions::new().normalizer(Normalizer::new("return text.trim();"));
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(
+            json_value["normalizer"]["__tlNormalizer"]["body"],
+            "return text.trim();"
+        );
+    }
+
     #[test]
     fn test_simple_options_json_string() {
         let options = SimpleOptions::new().exact(true);
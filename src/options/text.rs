@@ -0,0 +1,146 @@
+use crate::options::common::{Normalizer, TestingLibraryOptions};
+use serde::{Serialize, Serializer};
+
+/// Elements ignored by text queries
+#[derive(Debug, Clone)]
+pub enum Ignore {
+    /// CSS selector of the ignored elements, defaults to "script, style"
+    Selector(String),
+    /// Do not ignore any element
+    Nothing,
+}
+
+impl Serialize for Ignore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Ignore::Selector(selector) => selector.serialize(serializer),
+            Ignore::Nothing => false.serialize(serializer),
+        }
+    }
+}
+
+impl From<&str> for Ignore {
+    fn from(selector: &str) -> Self {
+        Ignore::Selector(selector.to_string())
+    }
+}
+
+impl From<String> for Ignore {
+    fn from(selector: String) -> Self {
+        Ignore::Selector(selector)
+    }
+}
+
+/// Options for text queries
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ByTextOptions {
+    /// CSS selector to filter elements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// Whether to use exact text matching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact: Option<bool>,
+    /// Elements to ignore, defaults to "script, style"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<Ignore>,
+    /// Whether to trim whitespace around the text, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<bool>,
+    /// Whether to collapse inner whitespace into a single space, defaults to true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse_whitespace: Option<bool>,
+    /// Custom normalizer, cannot be combined with trim and collapse_whitespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalizer: Option<Normalizer>,
+}
+
+impl ByTextOptions {
+    /// Create a new empty ByTextOptions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the selector option
+    pub fn selector(mut self, selector: impl Into<String>) -> Self {
+        self.selector = Some(selector.into());
+        self
+    }
+
+    /// Set the exact option
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = Some(exact);
+        self
+    }
+
+    /// Set the ignore option
+    pub fn ignore(mut self, ignore: impl Into<Ignore>) -> Self {
+        self.ignore = Some(ignore.into());
+        self
+    }
+
+    /// Set the trim option
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = Some(trim);
+        self
+    }
+
+    /// Set the collapse_whitespace option
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.collapse_whitespace = Some(collapse_whitespace);
+        self
+    }
+
+    /// Set the normalizer option
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Check if no option is set
+    pub fn is_empty(&self) -> bool {
+        self.selector.is_none()
+            && self.exact.is_none()
+            && self.ignore.is_none()
+            && self.trim.is_none()
+            && self.collapse_whitespace.is_none()
+            && self.normalizer.is_none()
+    }
+}
+
+impl TestingLibraryOptions for ByTextOptions {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_options_empty_serialization() {
+        let options = ByTextOptions::new();
+        assert_eq!(options.to_json_string().unwrap(), "{}");
+        assert!(options.is_empty());
+    }
+
+    #[test]
+    fn test_text_options_selector_serialization() {
+        let options = ByTextOptions::new().selector("p").exact(false);
+
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["selector"], "p");
+        assert_eq!(json_value["exact"], false);
+    }
+
+    #[test]
+    fn test_text_options_ignore_serialization() {
+        let options = ByTextOptions::new().ignore("script, style, .sr-only");
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["ignore"], "script, style, .sr-only");
+
+        let options = ByTextOptions::new().ignore(Ignore::Nothing);
+        let json_value = options.to_json_value().unwrap();
+        assert_eq!(json_value["ignore"], false);
+    }
+}
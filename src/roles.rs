@@ -0,0 +1,65 @@
+use serde::Serialize;
+
+/// Options for `Screen::roles_with` and `Screen::log_roles_with`, like Testing Library `getRoles`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolesOptions {
+    /// Include the elements excluded from the accessibility tree, like `ByRoleOptions::hidden`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+}
+
+impl RolesOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the hidden option
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+}
+
+/// Script calling `getRoles` or `logRoles` on a container (`null` for the document) with
+/// the given options. `logRoles` only prints to the console, its output is captured and returned.
+pub(crate) const ROLES_SCRIPT: &str = r#"
+    const [helper, container, options] = arguments;
+    if (helper === "getRoles") {
+        return window.__TL__.getRoles(container || document.body, options);
+    }
+    const log = console.log;
+    const output = [];
+    console.log = (...messages) => output.push(messages.join(" "));
+    try {
+        window.__TL__.logRoles(container || document.body, options);
+    } finally {
+        console.log = log;
+    }
+    return output.join("\n");
+"#;
+
+/// Script checking if its element argument is excluded from the accessibility tree
+pub(crate) const IS_INACCESSIBLE_SCRIPT: &str = r#"
+    const [element] = arguments;
+    return window.__TL__.isInaccessible(element);
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_roles_options_json() {
+        assert_eq!(
+            serde_json::to_value(RolesOptions::new()).unwrap(),
+            json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(RolesOptions::new().hidden(true)).unwrap(),
+            json!({ "hidden": true })
+        );
+    }
+}
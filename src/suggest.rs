@@ -0,0 +1,174 @@
+use crate::error::{TestingLibraryError, TestingLibraryResult};
+use crate::{By, TextMatch};
+use serde_json::Value;
+
+/// Query method a suggestion is restricted to, like the `method` argument of `getSuggestedQuery`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryMethod {
+    /// Suggest a `By::role` query
+    Role,
+    /// Suggest a `By::label_text` query
+    LabelText,
+    /// Suggest a `By::placeholder_text` query
+    PlaceholderText,
+    /// Suggest a `By::text` query
+    Text,
+    /// Suggest a `By::display_value` query
+    DisplayValue,
+    /// Suggest a `By::alt_text` query
+    AltText,
+    /// Suggest a `By::title` query
+    Title,
+    /// Suggest a `By::test_id` query
+    TestId,
+}
+
+impl QueryMethod {
+    /// Name of the method in Testing Library, like `LabelText`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryMethod::Role => "Role",
+            QueryMethod::LabelText => "LabelText",
+            QueryMethod::PlaceholderText => "PlaceholderText",
+            QueryMethod::Text => "Text",
+            QueryMethod::DisplayValue => "DisplayValue",
+            QueryMethod::AltText => "AltText",
+            QueryMethod::Title => "Title",
+            QueryMethod::TestId => "TestId",
+        }
+    }
+}
+
+/// Script suggesting a query for an element, for the given method or the best one when `null`.
+/// Regexes can't cross WebDriver so they are returned as `{regex: "/source/flags"}`.
+pub(crate) const SUGGESTED_QUERY_SCRIPT: &str = r#"
+    const [element, method] = arguments;
+    const serialize = (value) => (value instanceof RegExp ? { regex: `/${value.source}/${value.flags}` } : value);
+    const suggestion = window.__TL__.getSuggestedQuery(element, "get", method || undefined);
+    if (!suggestion) return null;
+    const [content, options] = suggestion.queryArgs;
+    return {
+        queryName: suggestion.queryName,
+        content: serialize(content),
+        options: Object.fromEntries(Object.entries(options || {}).map(([key, value]) => [key, serialize(value)])),
+    };
+"#;
+
+/// Convert the suggestion returned by `SUGGESTED_QUERY_SCRIPT` into a selector
+pub(crate) fn suggestion_to_by(suggestion: &Value) -> TestingLibraryResult<Option<By>> {
+    if suggestion.is_null() {
+        return Ok(None);
+    }
+
+    let content = text_match(&suggestion["content"])?;
+    let options = &suggestion["options"];
+    let by = match suggestion["queryName"].as_str().unwrap_or_default() {
+        "Role" => {
+            let mut selector = By::role(content.text_value());
+            if !options["name"].is_null() {
+                selector = selector.name(text_match(&options["name"])?);
+            }
+            if let Some(hidden) = options["hidden"].as_bool() {
+                selector = selector.hidden(hidden);
+            }
+            selector.into()
+        }
+        "LabelText" => By::label_text(content).into(),
+        "PlaceholderText" => By::placeholder_text(content).into(),
+        "Text" => By::text(content).into(),
+        "DisplayValue" => By::display_value(content).into(),
+        "AltText" => By::alt_text(content).into(),
+        "Title" => By::title(content).into(),
+        "TestId" => By::test_id(content).into(),
+        query_name => {
+            return Err(TestingLibraryError::ScriptSerialization(format!(
+                "getSuggestedQuery returned an unknown query {query_name}"
+            )))
+        }
+    };
+    Ok(Some(by))
+}
+
+/// Convert a suggested string or `{regex}` into a text match
+fn text_match(value: &Value) -> TestingLibraryResult<TextMatch> {
+    match (value.as_str(), value["regex"].as_str()) {
+        (Some(text), _) => Ok(TextMatch::literal(text)),
+        (None, Some(regex)) => Ok(TextMatch::Regex(regex.to_string())),
+        _ => Err(TestingLibraryError::ScriptSerialization(format!(
+            "getSuggestedQuery returned an unexpected argument {value}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_role_suggestion() {
+        let by = suggestion_to_by(&json!({
+            "queryName": "Role",
+            "content": "button",
+            "options": { "name": { "regex": "/save/i" } }
+        }))
+        .unwrap()
+        .unwrap();
+
+        let By::Role(role, Some(crate::Options::Role(options))) = by else {
+            panic!("expected a role selector, got {by:?}");
+        };
+        assert_eq!(role.text_value(), "button");
+        assert!(options.name.as_ref().unwrap().is_regex());
+        assert_eq!(options.name.as_ref().unwrap().text_value(), "/save/i");
+        assert_eq!(options.hidden, None);
+    }
+
+    #[test]
+    fn test_hidden_role_suggestion() {
+        let by = suggestion_to_by(&json!({
+            "queryName": "Role",
+            "content": "button",
+            "options": { "name": { "regex": "/secret/i" }, "hidden": true }
+        }))
+        .unwrap()
+        .unwrap();
+
+        let By::Role(_, Some(crate::Options::Role(options))) = by else {
+            panic!("expected a role selector, got {by:?}");
+        };
+        assert_eq!(options.hidden, Some(true));
+    }
+
+    #[test]
+    fn test_text_suggestions() {
+        let by = suggestion_to_by(&json!({
+            "queryName": "LabelText",
+            "content": { "regex": "/email/i" },
+            "options": {}
+        }))
+        .unwrap()
+        .unwrap();
+        assert!(matches!(by, By::LabelText(ref text, _) if text.text_value() == "/email/i"));
+
+        let by = suggestion_to_by(&json!({
+            "queryName": "TestId",
+            "content": "/odd-id/",
+            "options": {}
+        }))
+        .unwrap()
+        .unwrap();
+        assert!(matches!(by, By::TestId(ref text, _) if text.is_string()));
+    }
+
+    #[test]
+    fn test_no_suggestion() {
+        assert!(suggestion_to_by(&Value::Null).unwrap().is_none());
+        assert!(suggestion_to_by(&json!({
+            "queryName": "Unknown",
+            "content": "x",
+            "options": {}
+        }))
+        .is_err());
+    }
+}
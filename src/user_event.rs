@@ -0,0 +1,251 @@
+use crate::error::TestingLibraryResult;
+use crate::{By, Screen};
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+use thirtyfour::WebElement;
+
+/// Element an interaction is performed on, either already resolved or resolved with `Screen::get`
+// Targets are short lived arguments, boxing the selector would only make matching on them harder
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum Target {
+    /// An element already found
+    Element(WebElement),
+    /// A selector resolved with `Screen::get` right before the interaction
+    Selector(By),
+}
+
+impl Target {
+    /// Resolve the target into an element
+    pub(crate) async fn resolve(self, screen: &Screen) -> TestingLibraryResult<WebElement> {
+        match self {
+            Target::Element(element) => Ok(element),
+            Target::Selector(selector) => screen.get(selector).await,
+        }
+    }
+}
+
+impl From<WebElement> for Target {
+    fn from(element: WebElement) -> Self {
+        Target::Element(element)
+    }
+}
+
+impl From<&WebElement> for Target {
+    fn from(element: &WebElement) -> Self {
+        Target::Element(element.clone())
+    }
+}
+
+impl<T: Into<By>> From<T> for Target {
+    fn from(selector: T) -> Self {
+        Target::Selector(selector.into())
+    }
+}
+
+/// Options given to `userEvent.setup`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserEventOptions {
+    /// Delay between two actions, defaults to 0ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u64>,
+    /// Skip the hover events fired before a click
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_hover: Option<bool>,
+    /// Skip the click on the element before typing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_click: Option<bool>,
+}
+
+impl UserEventOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the delay option
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(u64::try_from(delay.as_millis()).unwrap_or(u64::MAX));
+        self
+    }
+
+    /// Set the skip_hover option
+    pub fn skip_hover(mut self, skip_hover: bool) -> Self {
+        self.skip_hover = Some(skip_hover);
+        self
+    }
+
+    /// Set the skip_click option
+    pub fn skip_click(mut self, skip_click: bool) -> Self {
+        self.skip_click = Some(skip_click);
+        self
+    }
+}
+
+/// Realistic user interactions backed by `@testing-library/user-event`
+///
+/// Every call sets up a new user-event instance, so keys held with `keyboard("{Shift>}")`
+/// are released at the end of the call.
+#[derive(Debug, Clone)]
+pub struct UserEvent {
+    screen: Screen,
+    options: UserEventOptions,
+}
+
+impl UserEvent {
+    /// Script running the named user-event action on an element (`null` for actions on the
+    /// focused element) with the action arguments and the setup options.
+    const USER_EVENT_SCRIPT: &str = r#"
+        const [action, element, args, options] = arguments;
+        const user = window.__TL__.userEvent.setup(options);
+        return element ? user[action](element, ...args) : user[action](...args);
+    "#;
+
+    pub(crate) fn new(screen: Screen, options: UserEventOptions) -> Self {
+        Self { screen, options }
+    }
+
+    /// Click the element, firing the pointer, mouse and focus events
+    pub async fn click(&self, target: impl Into<Target>) -> TestingLibraryResult<()> {
+        self.on_element("click", target, vec![]).await
+    }
+
+    /// Double click the element
+    pub async fn dbl_click(&self, target: impl Into<Target>) -> TestingLibraryResult<()> {
+        self.on_element("dblClick", target, vec![]).await
+    }
+
+    /// Click the element then type the text, supports user-event key descriptors like `{Enter}`
+    pub async fn type_text(
+        &self,
+        target: impl Into<Target>,
+        text: &str,
+    ) -> TestingLibraryResult<()> {
+        self.on_element("type", target, vec![Value::from(text)])
+            .await
+    }
+
+    /// Select the content of an editable element and delete it
+    pub async fn clear(&self, target: impl Into<Target>) -> TestingLibraryResult<()> {
+        self.on_element("clear", target, vec![]).await
+    }
+
+    /// Select options of a select or listbox by value or label
+    pub async fn select_options(
+        &self,
+        target: impl Into<Target>,
+        values: &[&str],
+    ) -> TestingLibraryResult<()> {
+        self.on_element("selectOptions", target, vec![Value::from(values)])
+            .await
+    }
+
+    /// Deselect options of a multiple select or listbox by value or label
+    pub async fn deselect_options(
+        &self,
+        target: impl Into<Target>,
+        values: &[&str],
+    ) -> TestingLibraryResult<()> {
+        self.on_element("deselectOptions", target, vec![Value::from(values)])
+            .await
+    }
+
+    /// Move the pointer over the element
+    pub async fn hover(&self, target: impl Into<Target>) -> TestingLibraryResult<()> {
+        self.on_element("hover", target, vec![]).await
+    }
+
+    /// Move the pointer out of the element
+    pub async fn unhover(&self, target: impl Into<Target>) -> TestingLibraryResult<()> {
+        self.on_element("unhover", target, vec![]).await
+    }
+
+    /// Move the focus to the next focusable element
+    pub async fn tab(&self) -> TestingLibraryResult<()> {
+        self.run("tab", None, vec![]).await
+    }
+
+    /// Move the focus to the previous focusable element
+    pub async fn shift_tab(&self) -> TestingLibraryResult<()> {
+        self.run("tab", None, vec![serde_json::json!({ "shift": true })])
+            .await
+    }
+
+    /// Press keys on the focused element, supports user-event key descriptors like `{Shift>}A{/Shift}`
+    pub async fn keyboard(&self, text: &str) -> TestingLibraryResult<()> {
+        self.run("keyboard", None, vec![Value::from(text)]).await
+    }
+
+    /// Paste the text in the focused element
+    pub async fn paste(&self, text: &str) -> TestingLibraryResult<()> {
+        self.run("paste", None, vec![Value::from(text)]).await
+    }
+
+    /// Resolve the target and run the action on it
+    async fn on_element(
+        &self,
+        action: &str,
+        target: impl Into<Target>,
+        arguments: Vec<Value>,
+    ) -> TestingLibraryResult<()> {
+        let element = target.into().resolve(&self.screen).await?;
+        self.run(action, Some(&element), arguments).await
+    }
+
+    /// Run a user-event action
+    async fn run(
+        &self,
+        action: &str,
+        element: Option<&WebElement>,
+        arguments: Vec<Value>,
+    ) -> TestingLibraryResult<()> {
+        let element = match element {
+            Some(element) => element.to_json()?,
+            None => Value::Null,
+        };
+        let arguments = vec![
+            Value::from(action),
+            element,
+            Value::Array(arguments),
+            serde_json::to_value(&self.options)?,
+        ];
+
+        self.screen
+            .query_executor()
+            .execute_catching(Self::USER_EVENT_SCRIPT, arguments)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_from_selector() {
+        assert!(matches!(
+            Target::from(By::role("button").name("Save")),
+            Target::Selector(By::Role(_, _))
+        ));
+        assert!(matches!(
+            Target::from(By::text("Save")),
+            Target::Selector(By::Text(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_user_event_options_serialization() {
+        let options = UserEventOptions::new()
+            .delay(Duration::from_millis(10))
+            .skip_hover(true);
+
+        let json_value = serde_json::to_value(options).unwrap();
+        assert_eq!(
+            json_value,
+            serde_json::json!({"delay": 10, "skipHover": true})
+        );
+    }
+}
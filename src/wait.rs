@@ -0,0 +1,209 @@
+use crate::configure;
+use crate::error::{TestingLibraryError, TestingLibraryResult};
+use crate::Screen;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thirtyfour::support::sleep;
+
+/// Timeout used by Testing Library async utilities when none is configured
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Interval between two checks used by Testing Library async utilities
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Function building the error returned when a wait times out
+pub type OnTimeout = Arc<dyn Fn(TestingLibraryError) -> TestingLibraryError + Send + Sync>;
+
+/// Options for `Screen::wait_for_with` and `Screen::wait_for_element_to_be_removed_with`
+#[derive(Clone, Default)]
+pub struct WaitForOptions {
+    /// How long to wait before failing, defaults to `async_util_timeout`
+    pub timeout: Option<Duration>,
+    /// How long to wait between two checks, defaults to 50ms
+    pub interval: Option<Duration>,
+    /// Customize the error returned on timeout
+    pub on_timeout: Option<OnTimeout>,
+}
+
+impl WaitForOptions {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout option
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the interval option
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Set the on_timeout option
+    pub fn on_timeout(
+        mut self,
+        on_timeout: impl Fn(TestingLibraryError) -> TestingLibraryError + Send + Sync + 'static,
+    ) -> Self {
+        self.on_timeout = Some(Arc::new(on_timeout));
+        self
+    }
+}
+
+impl fmt::Debug for WaitForOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitForOptions")
+            .field("timeout", &self.timeout)
+            .field("interval", &self.interval)
+            .field("on_timeout", &self.on_timeout.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+/// Retrying assertions returned by `Screen::eventually`
+#[derive(Debug, Clone)]
+pub struct Eventually {
+    screen: Screen,
+    options: WaitForOptions,
+}
+
+impl Eventually {
+    pub(crate) fn new(screen: Screen, timeout: Option<Duration>) -> Self {
+        Self {
+            screen,
+            options: WaitForOptions {
+                timeout,
+                ..WaitForOptions::default()
+            },
+        }
+    }
+
+    /// Set how long to wait between two attempts, defaults to 50ms
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.options.interval = Some(interval);
+        self
+    }
+
+    /// Calls the assertion with the screen until it succeeds,
+    /// on timeout the error carries the last failure
+    pub async fn assert<F, Fut, T>(self, callback: F) -> TestingLibraryResult<T>
+    where
+        F: FnMut(Screen) -> Fut,
+        Fut: Future<Output = TestingLibraryResult<T>>,
+    {
+        self.screen.wait_for_with(callback, self.options).await
+    }
+}
+
+/// Timeout of the async utilities, from `configure::Options::async_util_timeout`
+pub(crate) fn async_util_timeout(options: Option<&configure::Options>) -> Duration {
+    options
+        .and_then(|options| options.async_util_timeout)
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_TIMEOUT)
+}
+
+/// Call `check` until it succeeds or the timeout is reached
+pub(crate) async fn wait_until<F, Fut, T>(
+    options: WaitForOptions,
+    default_timeout: Duration,
+    mut check: F,
+) -> TestingLibraryResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = TestingLibraryResult<T>>,
+{
+    let timeout = options.timeout.unwrap_or(default_timeout);
+    let interval = options.interval.unwrap_or(DEFAULT_INTERVAL);
+    let start = Instant::now();
+
+    loop {
+        let last_error = match check().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            let error = TestingLibraryError::WaitTimeout {
+                timeout,
+                last_error: Box::new(last_error),
+            };
+            return Err(match options.on_timeout {
+                Some(on_timeout) => on_timeout(error),
+                None => error,
+            });
+        }
+
+        sleep(interval.min(timeout - elapsed)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thirtyfour::support::block_on;
+
+    #[test]
+    fn test_wait_until_retries() {
+        let mut calls = 0;
+        let result = block_on(wait_until(
+            WaitForOptions::new().interval(Duration::from_millis(1)),
+            DEFAULT_TIMEOUT,
+            || {
+                calls += 1;
+                let calls = calls;
+                async move {
+                    if calls < 3 {
+                        Err(TestingLibraryError::LibraryNotInjected)
+                    } else {
+                        Ok(calls)
+                    }
+                }
+            },
+        ));
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_wait_until_timeout() {
+        let options = WaitForOptions::new()
+            .timeout(Duration::from_millis(20))
+            .interval(Duration::from_millis(5));
+        let result: TestingLibraryResult<()> =
+            block_on(wait_until(options, DEFAULT_TIMEOUT, || async {
+                Err(TestingLibraryError::LibraryNotInjected)
+            }));
+
+        let error = result.unwrap_err();
+        assert!(matches!(error, TestingLibraryError::WaitTimeout { .. }));
+        assert_eq!(
+            error.to_string(),
+            "Timed out after 20ms: Testing Library is not injected in the page"
+        );
+    }
+
+    #[test]
+    fn test_wait_until_on_timeout() {
+        let options = WaitForOptions::new()
+            .timeout(Duration::ZERO)
+            .on_timeout(|error| TestingLibraryError::Javascript {
+                name: "Error".to_string(),
+                message: format!("spinner still visible ({error})"),
+            });
+        let result: TestingLibraryResult<()> =
+            block_on(wait_until(options, DEFAULT_TIMEOUT, || async {
+                Err(TestingLibraryError::LibraryNotInjected)
+            }));
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Error: spinner still visible (Timed out after 0ms"));
+    }
+}
@@ -47,9 +47,19 @@ import {
   getByTestId,
   findAllByTestId,
   findByTestId,
+  buildQueries,
   configure,
+  createEvent,
+  fireEvent,
+  getRoles,
+  getSuggestedQuery,
+  isInaccessible,
+  logRoles,
+  prettyDOM,
   screen,
 } from "@testing-library/dom"
+import userEvent from "@testing-library/user-event"
+import { computeAccessibleDescription, computeAccessibleName } from "dom-accessibility-api"
 
 window.__TL__ = {
   queryAllByRole,
@@ -100,6 +110,17 @@ window.__TL__ = {
   getByTestId,
   findAllByTestId,
   findByTestId,
+  buildQueries,
   configure,
-  logTestingPlaygroundURL: screen.logTestingPlaygroundURL
+  createEvent,
+  fireEvent,
+  getRoles,
+  getSuggestedQuery,
+  isInaccessible,
+  logRoles,
+  prettyDOM,
+  logTestingPlaygroundURL: screen.logTestingPlaygroundURL,
+  userEvent,
+  computeAccessibleName,
+  computeAccessibleDescription,
 }
\ No newline at end of file
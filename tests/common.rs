@@ -90,7 +90,7 @@ pub fn get_limiter() -> &'static Semaphore {
 /// Locks the Firefox browser for exclusive use.
 ///
 /// This ensures there is only ever one Firefox browser running at a time.
-pub async fn lock_firefox<'a>(browser: &str) -> Option<SemaphorePermit<'static>> {
+pub async fn lock_firefox(browser: &str) -> Option<SemaphorePermit<'static>> {
     if browser == "firefox" {
         Some(get_limiter().acquire().await.unwrap())
     } else {
@@ -239,7 +239,7 @@ pub fn assert_none<T>(result: Option<T>) -> WebDriverResult<()> {
     Ok(())
 }
 
-pub fn assert_error<T>(result: WebDriverResult<T>) -> WebDriverResult<()> {
+pub fn assert_error<T, E>(result: Result<T, E>) -> WebDriverResult<()> {
     assert!(result.is_err());
     Ok(())
 }
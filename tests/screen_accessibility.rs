@@ -0,0 +1,69 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{expect, By, TestingLibraryError, TextMatch};
+
+#[rstest]
+fn test_accessible_name_and_description(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("accessibility.html").await?;
+
+        let close = screen.get(By::button()).await?;
+        assert_eq!(screen.accessible_name(&close).await?, "Close dialog");
+        assert_eq!(
+            screen.accessible_description(&close).await?,
+            "Unsaved changes will be lost"
+        );
+
+        let email = screen.get(By::textbox()).await?;
+        assert_eq!(screen.accessible_name(&email).await?, "Email address");
+        assert_eq!(
+            screen.accessible_description(&email).await?,
+            "We never share it"
+        );
+
+        let logo = screen.get(By::img()).await?;
+        assert_eq!(screen.accessible_name(&logo).await?, "Company logo");
+        assert_eq!(screen.accessible_description(&logo).await?, "");
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_accessible_name_assertions(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("accessibility.html").await?;
+
+        let close = screen.get(By::button()).await?;
+        expect(&close)
+            .to_have_accessible_name("Close dialog")
+            .await?;
+        expect(&close)
+            .to_have_accessible_name(TextMatch::Regex("/close/i".to_string()))
+            .await?;
+        expect(&close)
+            .not()
+            .to_have_accessible_name("Close")
+            .await?;
+        expect(&close)
+            .to_have_accessible_description("Unsaved changes will be lost")
+            .await?;
+
+        let result = expect(&close).to_have_accessible_description("Saved").await;
+        match result {
+            Err(TestingLibraryError::AssertionFailed(failure)) => {
+                assert_eq!(
+                    failure.assertion,
+                    "expect(element).toHaveAccessibleDescription()"
+                );
+                assert!(failure.message.contains("\"Unsaved changes will be lost\""));
+            }
+            other => panic!("expected an assertion failure, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
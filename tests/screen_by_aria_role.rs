@@ -0,0 +1,52 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{AriaRole, By};
+
+#[rstest]
+fn test_aria_role_enum(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_aria_role.html").await?;
+
+        let button = screen.get(By::role(AriaRole::Button)).await?;
+        assert_id(&button, "save-btn").await?;
+
+        let link = screen.get(By::role(AriaRole::Link).name("Help")).await?;
+        assert_id(&link, "help-link").await?;
+
+        let section = screen
+            .get(By::role(AriaRole::Custom("doc-abstract".to_string())))
+            .await?;
+        assert_id(&section, "abstract").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_role_constructors(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_aria_role.html").await?;
+
+        let heading = screen.get(By::heading(1)).await?;
+        assert_id(&heading, "title-heading").await?;
+
+        let headings = screen.get_all(By::heading(2)).await?;
+        assert_count(&headings, 2)?;
+
+        let heading = screen.get(By::heading(2).name("Settings")).await?;
+        assert_id(&heading, "settings-heading").await?;
+
+        let textbox = screen.get(By::textbox().name("Username")).await?;
+        assert_id(&textbox, "username").await?;
+
+        let button = screen.find(By::button().name("Save")).await?;
+        assert_id(&button, "save-btn").await?;
+
+        assert_none(screen.query(By::heading(3)).await?)?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,86 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, FunctionMatcher, TextMatch};
+
+#[rstest]
+fn test_text_content_equals(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_function_matcher.html")
+            .await?;
+
+        // The text is broken up by multiple elements so a string match fails
+        assert_error(screen.get(By::text("Hello brave new world")).await)?;
+
+        let element = screen
+            .get(By::text(FunctionMatcher::text_content_equals(
+                "Hello brave new world",
+            )))
+            .await?;
+        assert_id(&element, "split-text").await?;
+
+        let element = screen
+            .get(By::text(FunctionMatcher::text_content_contains(
+                "brave new",
+            )))
+            .await?;
+        assert_id(&element, "split-text").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_function(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_function_matcher.html")
+            .await?;
+
+        let element = screen
+            .get(By::text(TextMatch::function(
+                "return element.tagName === 'SPAN' && content.startsWith('Hello');",
+            )))
+            .await?;
+        assert_id(&element, "greeting-span").await?;
+
+        let element = screen
+            .get(By::placeholder_text(FunctionMatcher::with_args(
+                "return content.endsWith(args);",
+                "email",
+            )))
+            .await?;
+        assert_id(&element, "email").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_role_name_function(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_function_matcher.html")
+            .await?;
+
+        let button = screen
+            .get(
+                By::role("button")
+                    .name(TextMatch::function("return content.startsWith('Delete');")),
+            )
+            .await?;
+        assert_id(&button, "delete-btn").await?;
+
+        let buttons = screen
+            .get_all(
+                By::role("button").name(TextMatch::function("return /item \\d+$/.test(content);")),
+            )
+            .await?;
+        assert_count(&buttons, 2)?;
+
+        Ok(())
+    })
+}
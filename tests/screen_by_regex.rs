@@ -3,7 +3,7 @@ use common::*;
 use rstest::rstest;
 use thirtyfour::prelude::*;
 use thirtyfour::support::block_on;
-use thirtyfour_testing_library_ext::By;
+use thirtyfour_testing_library_ext::{By, TestingLibraryError, TextMatch};
 
 #[rstest]
 fn test_text_regex(test_harness: TestHarness) -> WebDriverResult<()> {
@@ -93,3 +93,108 @@ fn test_label_text_regex(test_harness: TestHarness) -> WebDriverResult<()> {
         Ok(())
     })
 }
+
+#[rstest]
+fn test_text_rust_regex(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_exact.html").await?;
+
+        let regex = regex::Regex::new(r"(?i)^please\s+login\s+here$").unwrap();
+        let element = screen.get(By::text(TextMatch::regex(&regex)?)).await?;
+        assert_id(&element, "text-partial").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_role_name_rust_regex(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_options.html").await?;
+
+        let regex = regex::Regex::new(r"^Save (?P<what>\w+)$").unwrap();
+        let button = screen
+            .get(By::role("button").name(TextMatch::try_from(regex)?))
+            .await?;
+        assert_text(&button, "Save Document").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_rust_regex_perl_classes_are_unicode(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_regex_unicode.html")
+            .await?;
+        let regex = |pattern: &str| TextMatch::regex(&regex::Regex::new(pattern).unwrap());
+
+        let element = screen.get(By::text(regex(r"^\w+ \w+$")?)).await?;
+        assert_id(&element, "cafe").await?;
+
+        let element = screen.get(By::text(regex(r"^Order \d+$")?)).await?;
+        assert_id(&element, "order").await?;
+
+        let element = screen.get(By::text(regex(r"\bcrème\b")?)).await?;
+        assert_id(&element, "cafe").await?;
+        assert_none(screen.query(By::text(regex(r"\bcrè\b")?)).await?)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_rust_regex_unicode_classes_and_escapes(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_regex_unicode.html")
+            .await?;
+        let regex = |pattern: &str| TextMatch::regex(&regex::Regex::new(pattern).unwrap());
+
+        let element = screen.get(By::text(regex(r"^\p{Greek}+$")?)).await?;
+        assert_id(&element, "athens").await?;
+
+        let element = screen.get(By::text(regex(r"^\p{greek}+$")?)).await?;
+        assert_id(&element, "athens").await?;
+
+        let element = screen
+            .get(By::text(regex(r"^\p{Lu }\p{sc=Greek}+$")?))
+            .await?;
+        assert_id(&element, "athens").await?;
+
+        let element = screen.get(By::text(regex(r"\U0001F600$")?)).await?;
+        assert_id(&element, "smile").await?;
+
+        let element = screen.get(By::text(regex(r"Caf\u00E9")?)).await?;
+        assert_id(&element, "cafe").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_unsupported_rust_regex() -> WebDriverResult<()> {
+    let regex = regex::Regex::new(r"(?x) save \s+ document").unwrap();
+    let result = TextMatch::regex(&regex);
+    assert!(matches!(
+        result,
+        Err(TestingLibraryError::UnsupportedRegex { .. })
+    ));
+
+    Ok(())
+}
+
+#[rstest]
+fn test_text_literal_is_not_a_regex(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_escaping.html")
+            .await?;
+
+        let element = screen.get(By::text(TextMatch::literal("/api/"))).await?;
+        assert_id(&element, "slashes").await?;
+
+        Ok(())
+    })
+}
@@ -498,7 +498,7 @@ fn test_value_min(test_harness: TestHarness) -> WebDriverResult<()> {
         let screen = test_harness.screen_for_page("by_role_options.html").await?;
 
         let value_opts = ValueOptions {
-            min: Some(0),
+            min: Some(0.0),
             max: None,
             now: None,
             text: None,
@@ -569,7 +569,7 @@ fn test_value_max(test_harness: TestHarness) -> WebDriverResult<()> {
 
         let value_opts = ValueOptions {
             min: None,
-            max: Some(50),
+            max: Some(50.0),
             now: None,
             text: None,
         };
@@ -640,7 +640,7 @@ fn test_value_now(test_harness: TestHarness) -> WebDriverResult<()> {
         let value_opts = ValueOptions {
             min: None,
             max: None,
-            now: Some(75),
+            now: Some(75.0),
             text: None,
         };
 
@@ -0,0 +1,123 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, TriState, ValueOptions};
+
+// 1. Tri-State Tests
+
+#[rstest]
+fn test_mixed_checked(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_states.html").await?;
+
+        let checkboxes = screen
+            .get_all(By::role("checkbox").checked(TriState::Mixed))
+            .await?;
+        assert_count(&checkboxes, 2)?;
+        assert_id(&checkboxes[0], "indeterminate-checkbox").await?;
+        assert_id(&checkboxes[1], "mixed-checkbox").await?;
+
+        let checkbox = screen
+            .get(
+                By::role("checkbox")
+                    .checked(TriState::Mixed)
+                    .name("Toppings"),
+            )
+            .await?;
+        assert_id(&checkbox, "mixed-checkbox").await?;
+
+        let checkbox = screen.get(By::role("checkbox").checked(true)).await?;
+        assert_id(&checkbox, "checked-checkbox").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_mixed_pressed(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_states.html").await?;
+
+        let button = screen
+            .get(By::role("button").pressed(TriState::Mixed))
+            .await?;
+        assert_id(&button, "mixed-btn").await?;
+
+        let button = screen.get(By::role("button").pressed(true)).await?;
+        assert_id(&button, "pressed-btn").await?;
+
+        assert_none(
+            screen
+                .query(By::role("button").pressed(TriState::Mixed).name("Bold"))
+                .await?,
+        )?;
+
+        Ok(())
+    })
+}
+
+// 2. Disabled And Invalid Tests
+
+#[rstest]
+fn test_disabled(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_states.html").await?;
+
+        let buttons = screen.get_all(By::role("button").disabled(true)).await?;
+        assert_count(&buttons, 3)?;
+        assert_id(&buttons[0], "disabled-btn").await?;
+        assert_id(&buttons[1], "aria-disabled-btn").await?;
+        assert_id(&buttons[2], "fieldset-disabled-btn").await?;
+
+        let button = screen
+            .get(By::role("button").disabled(false).name("Cancel"))
+            .await?;
+        assert_id(&button, "enabled-btn").await?;
+
+        assert_none(
+            screen
+                .query(By::role("button").disabled(false).name("Save"))
+                .await?,
+        )?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_invalid(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_states.html").await?;
+
+        let input = screen.get(By::role("textbox").invalid(true)).await?;
+        assert_id(&input, "invalid-input").await?;
+
+        let input = screen.get(By::role("textbox").invalid(false)).await?;
+        assert_id(&input, "valid-input").await?;
+
+        Ok(())
+    })
+}
+
+// 3. Fractional Value Tests
+
+#[rstest]
+fn test_fractional_value(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_states.html").await?;
+
+        let value_opts = ValueOptions {
+            min: Some(0.0),
+            max: Some(1.0),
+            now: Some(0.5),
+            text: None,
+        };
+
+        let slider = screen.get(By::role("slider").value(value_opts)).await?;
+        assert_id(&slider, "opacity-slider").await?;
+
+        Ok(())
+    })
+}
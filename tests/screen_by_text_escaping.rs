@@ -0,0 +1,65 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::By;
+
+#[rstest]
+fn test_text_with_quotes(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_escaping.html")
+            .await?;
+
+        let element = screen.get(By::text("Don't save")).await?;
+        assert_id(&element, "single-quote").await?;
+
+        let element = screen.get(By::text("Say \"hello\"")).await?;
+        assert_id(&element, "double-quote").await?;
+
+        let element = screen.get(By::role("button").name("Don't save")).await?;
+        assert_id(&element, "single-quote").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_text_with_backslashes(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_escaping.html")
+            .await?;
+
+        let element = screen.get(By::text("C:\\temp\\file.txt")).await?;
+        assert_id(&element, "backslash").await?;
+
+        let element = screen.get(By::text("/^C:\\\\temp/")).await?;
+        assert_id(&element, "backslash").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_text_is_not_evaluated(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_escaping.html")
+            .await?;
+
+        let element = screen
+            .get(By::text("'); window.injected = true; ('"))
+            .await?;
+        assert_id(&element, "injection").await?;
+
+        let injected = test_harness
+            .driver()
+            .execute("return window.injected === true;", vec![])
+            .await?;
+        assert_eq!(injected.json(), &serde_json::Value::Bool(false));
+
+        Ok(())
+    })
+}
@@ -0,0 +1,75 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, Normalizer};
+
+#[rstest]
+fn test_trim_and_collapse_whitespace(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_normalizer.html")
+            .await?;
+
+        // Whitespace is trimmed and collapsed by default
+        let element = screen.get(By::text("Keep these spaces")).await?;
+        assert_id(&element, "spaced-text").await?;
+
+        assert_none(
+            screen
+                .query(
+                    By::text("Keep these spaces")
+                        .trim(false)
+                        .collapse_whitespace(false),
+                )
+                .await?,
+        )?;
+
+        let element = screen
+            .get(
+                By::text("  Keep   these   spaces  ")
+                    .trim(false)
+                    .collapse_whitespace(false),
+            )
+            .await?;
+        assert_id(&element, "spaced-text").await?;
+
+        let element = screen
+            .get(
+                By::label_text("  Full    name  ")
+                    .trim(false)
+                    .collapse_whitespace(false),
+            )
+            .await?;
+        assert_id(&element, "spaced-input").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_normalizer(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("by_text_normalizer.html")
+            .await?;
+
+        let element = screen
+            .get(By::text("price: 42 eur").normalizer(Normalizer::with_args(
+                "return text.split(args).join(' ').toLowerCase();",
+                "\u{00a0}",
+            )))
+            .await?;
+        assert_id(&element, "nbsp-text").await?;
+
+        let element = screen
+            .get(By::label_text("FULL NAME").normalizer(Normalizer::new(
+                "return text.trim().replace(/\\s+/g, ' ').toUpperCase();",
+            )))
+            .await?;
+        assert_id(&element, "spaced-input").await?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,77 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, Ignore};
+
+// 1. Selector Option Tests
+
+#[rstest]
+fn test_selector_option(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_options.html").await?;
+
+        assert_error(screen.get(By::text("Total")).await)?;
+
+        let element = screen.get(By::text("Total").selector("p")).await?;
+        assert_id(&element, "total-paragraph").await?;
+
+        let element = screen.get(By::text("Total").selector("h2")).await?;
+        assert_id(&element, "total-heading").await?;
+
+        let elements = screen
+            .get_all(By::text("Total").selector("p, span"))
+            .await?;
+        assert_count(&elements, 2)?;
+
+        assert_none(screen.query(By::text("Total").selector("button")).await?)?;
+
+        Ok(())
+    })
+}
+
+// 2. Ignore Option Tests
+
+#[rstest]
+fn test_ignore_option(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_options.html").await?;
+
+        assert_error(screen.get(By::text("Close")).await)?;
+
+        let element = screen
+            .get(By::text("Close").ignore("script, style, .sr-only"))
+            .await?;
+        assert_id(&element, "close-button").await?;
+
+        let elements = screen
+            .get_all(By::text("Close").ignore(Ignore::Nothing))
+            .await?;
+        assert_count(&elements, 2)?;
+
+        Ok(())
+    })
+}
+
+// 3. Combined Options Tests
+
+#[rstest]
+fn test_selector_with_exact_option(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_options.html").await?;
+
+        let element = screen
+            .get(By::text("total").selector("span").exact(false))
+            .await?;
+        assert_id(&element, "total-span").await?;
+
+        assert_none(
+            screen
+                .query(By::text("total").selector("span").exact(true))
+                .await?,
+        )?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,118 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::components::{Component, ElementResolver};
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, By, TestingLibraryComponent};
+
+#[derive(Debug, Clone, TestingLibraryComponent)]
+struct CheckboxComponent {
+    base: WebElement,
+    #[tl(role = "checkbox")]
+    input: ElementResolver<WebElement>,
+}
+
+#[derive(Debug, Clone, TestingLibraryComponent)]
+struct LoginForm {
+    #[base]
+    form: WebElement,
+    #[tl(role = "heading", level = 2)]
+    heading: ElementResolver<WebElement>,
+    #[tl(label_text = "Email")]
+    email: ElementResolver<WebElement>,
+    #[tl(placeholder_text = "Pass", exact = false)]
+    password: ElementResolver<WebElement>,
+    #[tl(role = "button", name = "Save")]
+    save: ElementResolver<WebElement>,
+    #[tl(role = "listitem")]
+    options: ElementResolver<Vec<CheckboxComponent>>,
+    #[tl(role = "textbox", first)]
+    first_textbox: ElementResolver<WebElement>,
+    #[tl(role = "alert")]
+    alert: ElementResolver<Option<WebElement>>,
+    #[tl(role = "alert", allow_empty)]
+    alerts: ElementResolver<Vec<WebElement>>,
+    #[tl(role = "link", name = "Help", wait)]
+    help: ElementResolver<WebElement>,
+    submitted: bool,
+}
+
+#[rstest]
+fn test_component_fields(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("component.html").await?;
+
+        let form: LoginForm = screen.get(By::role("form").name("Login")).await?.into();
+        assert_id(&form.base_element(), "login").await?;
+        assert!(!form.submitted);
+
+        assert_id(&form.heading.resolve().await?, "login-heading").await?;
+        assert_id(&form.email.resolve().await?, "email").await?;
+        assert_id(&form.password.resolve().await?, "password").await?;
+        assert_id(&form.first_textbox.resolve().await?, "email").await?;
+
+        // The save button outside the form is not matched
+        assert_id(&form.save.resolve().await?, "save").await?;
+
+        let options = form.options.resolve().await?;
+        assert_count(&options, 2)?;
+        assert_id(&options[1].input.resolve().await?, "newsletter").await?;
+
+        assert_none(form.alert.resolve().await?)?;
+        assert_count(&form.alerts.resolve().await?, 0)?;
+
+        assert_id(&form.help.resolve().await?, "help").await?;
+
+        Ok(())
+    })
+}
+
+#[derive(Debug, Clone, TestingLibraryComponent)]
+struct MissingFields {
+    base: WebElement,
+    #[tl(role = "dialog")]
+    dialog: ElementResolver<WebElement>,
+    #[tl(role = "dialog")]
+    dialogs: ElementResolver<Vec<WebElement>>,
+}
+
+#[rstest]
+fn test_component_missing_fields(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("component.html").await?;
+
+        let missing = MissingFields::new(screen.get(By::role("form")).await?);
+        assert_error(missing.dialog.resolve().await)?;
+        assert_error(missing.dialogs.resolve().await)?;
+
+        Ok(())
+    })
+}
+
+#[derive(Debug, Clone, TestingLibraryComponent)]
+struct SubmitForm {
+    base: WebElement,
+    #[tl(test_id = "submit")]
+    submit: ElementResolver<WebElement>,
+}
+
+#[rstest]
+fn test_component_with_screen(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("component.html")
+            .await?
+            .configure(configure::Options::new().with_test_id_attribute("data-cy"));
+        let base = screen.get(By::role("form").name("Login")).await?;
+
+        let form = SubmitForm::with_screen(base.clone(), &screen);
+        assert_id(&form.submit.resolve().await?, "save").await?;
+
+        // Without the screen, fields use the default `data-testid` attribute
+        let form = SubmitForm::new(base);
+        assert_error(form.submit.resolve().await)?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,153 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use serde_json::{json, Value};
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, QueryErrorMessages, TestingLibraryError};
+
+const DATA_CY_QUERY: &str = "return container.querySelectorAll(`[data-cy=\"${value}\"]`);";
+
+const ROLE_DESCRIPTION_QUERY: &str = "
+    const cards = Array.from(container.querySelectorAll(`[aria-roledescription=\"${value}\"]`));
+    return options.text ? cards.filter((card) => card.textContent === options.text) : cards;
+";
+
+#[rstest]
+fn test_custom_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("custom_query.html").await?;
+        let screen = screen.register_query("DataCy", DATA_CY_QUERY, None).await?;
+
+        let save = screen
+            .get(By::custom("DataCy", "save", Value::Null))
+            .await?;
+        assert_id(&save, "save").await?;
+        assert_none(
+            screen
+                .query(By::custom("DataCy", "missing", Value::Null))
+                .await?,
+        )?;
+
+        let late = screen
+            .find(By::custom("DataCy", "late", Value::Null))
+            .await?;
+        assert_id(&late, "late-card").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_query_options_and_errors(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("custom_query.html").await?;
+        let screen = screen
+            .register_query(
+                "RoleDescription",
+                ROLE_DESCRIPTION_QUERY,
+                Some(QueryErrorMessages::new(
+                    "Several elements are described as {value}",
+                    "Unable to find an element described as {value}",
+                )),
+            )
+            .await?;
+
+        let receipt = screen
+            .get(By::custom(
+                "RoleDescription",
+                "card",
+                json!({ "text": "Receipt" }),
+            ))
+            .await?;
+        assert_id(&receipt, "receipt-card").await?;
+
+        match screen
+            .get(By::custom("RoleDescription", "card", Value::Null))
+            .await
+        {
+            Err(TestingLibraryError::MultipleElementsFound(failure)) => {
+                assert_eq!(failure.message, "Several elements are described as card")
+            }
+            other => panic!("expected multiple elements, got {other:?}"),
+        }
+        assert!(matches!(
+            screen
+                .find(By::custom("RoleDescription", "card", Value::Null))
+                .await,
+            Err(TestingLibraryError::MultipleElementsFound(_))
+        ));
+        assert!(matches!(
+            screen
+                .get(By::custom("RoleDescription", "banner", Value::Null))
+                .await,
+            Err(TestingLibraryError::NoElementFound(_))
+        ));
+        assert!(matches!(
+            screen.get(By::custom("Unknown", "card", Value::Null)).await,
+            Err(TestingLibraryError::UnknownQuery(_))
+        ));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_query_reinstalled_after_navigation(
+    test_harness: TestHarness,
+) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("custom_query.html").await?;
+        let screen = screen.register_query("DataCy", DATA_CY_QUERY, None).await?;
+
+        test_harness.driver().refresh().await?;
+
+        let cards = screen
+            .get_all(By::custom("DataCy", "invoice", Value::Null))
+            .await?;
+        assert_count(&cards, 1)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_query_registered_again(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("custom_query.html").await?;
+        let screen = screen.register_query("DataCy", DATA_CY_QUERY, None).await?;
+        assert_none(
+            screen
+                .query(By::custom("DataCy", "invoice-card", Value::Null))
+                .await?,
+        )?;
+
+        let screen = screen
+            .register_query(
+                "DataCy",
+                "return container.querySelectorAll(`#${value}`);",
+                None,
+            )
+            .await?;
+        let invoice = screen
+            .get(By::custom("DataCy", "invoice-card", Value::Null))
+            .await?;
+        assert_id(&invoice, "invoice-card").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_custom_query_built_in_name(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("custom_query.html").await?;
+
+        match screen.register_query("Role", DATA_CY_QUERY, None).await {
+            Err(TestingLibraryError::BuiltInQuery(name)) => assert_eq!(name, "Role"),
+            other => panic!("expected a built-in query error, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
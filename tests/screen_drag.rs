@@ -0,0 +1,40 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::By;
+
+#[rstest]
+fn test_drag(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("drag_to.html").await?;
+
+        let target = test_harness
+            .driver()
+            .find(thirtyfour::By::Id("target"))
+            .await?;
+        let dropped = screen.drag(By::role("img"), &target).await?;
+        assert!(dropped);
+
+        // The drop handler moved the image into the target
+        let image = screen.within(target).get(By::role("img")).await?;
+        assert_id(&image, "draggable").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_drag_rejected(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("drag_to.html").await?;
+
+        // The image does not accept drops, its dragover is not cancelled
+        let image = screen.get(By::role("img")).await?;
+        let dropped = screen.drag(&image, &image).await?;
+        assert!(!dropped);
+
+        Ok(())
+    })
+}
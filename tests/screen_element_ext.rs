@@ -0,0 +1,45 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, TestingLibraryDriverExt, TestingLibraryElementExt};
+
+#[rstest]
+fn test_element_ext_queries(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("element_ext.html").await?;
+
+        assert_count(&screen.get_all(By::role("button").name("Delete")).await?, 2)?;
+
+        let row = screen.get(By::role("row").name("Invoice 2 Delete")).await?;
+        let delete = row.get_by(By::role("button").name("Delete")).await?;
+        assert_id(&delete, "delete-2").await?;
+
+        assert_count(&row.get_all_by(By::role("cell")).await?, 2)?;
+        assert_none(row.query_by(By::text("Invoice 1")).await?)?;
+        assert_count(&row.query_all_by(By::text("Invoice 1")).await?, 0)?;
+
+        let archive = row.find_by(By::role("button").name("Archive")).await?;
+        assert_id(&archive, "archive-2").await?;
+        assert_count(&row.find_all_by(By::role("button")).await?, 2)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_driver_ext_screen(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        test_harness.screen_for_page("element_ext.html").await?;
+
+        let screen = test_harness.driver().screen().await?;
+        let rows = screen.get_all(By::role("row")).await?;
+        assert_count(&rows, 2)?;
+
+        let delete = rows[0].screen().get(By::role("button")).await?;
+        assert_id(&delete, "delete-1").await?;
+
+        Ok(())
+    })
+}
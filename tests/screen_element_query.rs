@@ -0,0 +1,134 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use std::time::Duration;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, By, TestingLibraryQueryable};
+
+#[rstest]
+fn test_driver_tl_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        test_harness.screen_for_page("element_query.html").await?;
+        let driver = test_harness.driver();
+
+        let save = driver
+            .tl_query(By::role("button").name("Save"))
+            .single()
+            .await?;
+        assert_id(&save, "save").await?;
+
+        // Polls until the element shows up
+        let publish = driver
+            .tl_query(By::role("button").name("Publish"))
+            .wait(Duration::from_secs(2), Duration::from_millis(50))
+            .first()
+            .await?;
+        assert_id(&publish, "publish").await?;
+
+        let buttons = driver
+            .tl_query(By::text("/save/i"))
+            .and_displayed()
+            .all_from_selector()
+            .await?;
+        assert_count(&buttons, 1)?;
+
+        assert!(driver
+            .tl_query(By::role("link"))
+            .nowait()
+            .first_opt()
+            .await?
+            .is_none());
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_element_tl_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        test_harness.screen_for_page("element_query.html").await?;
+        let form = test_harness
+            .driver()
+            .find(thirtyfour::By::Id("form"))
+            .await?;
+
+        let save = form
+            .tl_query(By::role("button").name("Save"))
+            .nowait()
+            .single()
+            .await?;
+        assert_id(&save, "save").await?;
+
+        let outside = form
+            .tl_query(By::text("Outside"))
+            .nowait()
+            .all_from_selector()
+            .await?;
+        assert_count(&outside, 0)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_tl_query_element_inserted_between_polls(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        test_harness.screen_for_page("element_query.html").await?;
+        let form = test_harness
+            .driver()
+            .find(thirtyfour::By::Id("form"))
+            .await?;
+
+        // The draft button is inserted first in the form once the query started polling
+        let draft = form
+            .tl_query(By::role("button").name("Draft"))
+            .wait(Duration::from_secs(2), Duration::from_millis(50))
+            .single()
+            .await?;
+        assert_id(&draft, "draft").await?;
+
+        let buttons = form
+            .tl_query(By::role("button"))
+            .nowait()
+            .all_from_selector()
+            .await?;
+        assert_count(&buttons, 3)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_screen_tl_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("element_query.html")
+            .await?
+            .configure(configure::Options::new().with_test_id_attribute("data-cy"));
+        let form = screen.get(By::role("form").name("Profile")).await?;
+        let html = form.outer_html().await?;
+
+        // The query keeps the configuration of the screen
+        let save = screen
+            .within(form.clone())
+            .tl_query(By::test_id("save"))
+            .and_displayed()
+            .nowait()
+            .single()
+            .await?;
+        assert_id(&save, "save").await?;
+
+        // Polling does not change the page
+        assert!(
+            screen
+                .tl_query(By::role("dialog"))
+                .wait(Duration::from_millis(200), Duration::from_millis(50))
+                .not_exists()
+                .await?
+        );
+        assert_eq!(form.outer_html().await?, html);
+
+        Ok(())
+    })
+}
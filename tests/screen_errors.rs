@@ -0,0 +1,134 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, FunctionMatcher, TestingLibraryError};
+
+#[rstest]
+fn test_no_element_found_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_exact.html").await?;
+
+        let error = screen.get(By::text("Missing")).await.unwrap_err();
+        assert!(matches!(error, TestingLibraryError::NoElementFound(_)));
+
+        let failure = error.query_failure().unwrap();
+        assert!(failure
+            .message
+            .starts_with("Unable to find an element with the text: Missing"));
+        assert!(failure.container.is_none());
+        assert!(failure.dom.as_ref().unwrap().contains("Please Login Here"));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_multiple_elements_found_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("screen_within.html").await?;
+
+        let error = screen.get(By::text("Some text")).await.unwrap_err();
+        assert!(matches!(
+            error,
+            TestingLibraryError::MultipleElementsFound(_)
+        ));
+
+        let error = screen.query(By::text("Some text")).await.unwrap_err();
+        assert!(matches!(
+            error,
+            TestingLibraryError::MultipleElementsFound(_)
+        ));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_find_timeout_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_exact.html").await?;
+
+        let error = screen.find(By::text("Missing")).await.unwrap_err();
+        assert!(matches!(error, TestingLibraryError::FindTimeout(_)));
+
+        let error = screen.find_all(By::text("Missing")).await.unwrap_err();
+        assert!(matches!(error, TestingLibraryError::FindTimeout(_)));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_within_error_carries_container(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("screen_within.html").await?;
+
+        let parent_element = test_harness
+            .driver()
+            .find(thirtyfour::prelude::By::Id("parent"))
+            .await?;
+        let error = screen
+            .within(parent_element.clone())
+            .get(By::text("Missing"))
+            .await
+            .unwrap_err();
+
+        let container = error.query_failure().unwrap().container.clone().unwrap();
+        assert_eq!(container.element_id(), parent_element.element_id());
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_invalid_regex_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_exact.html").await?;
+
+        let error = screen.get(By::text("/[/")).await.unwrap_err();
+        assert!(matches!(error, TestingLibraryError::InvalidRegex(_)));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_function_syntax_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_text_exact.html").await?;
+
+        let error = screen
+            .get(By::text(FunctionMatcher::new("return content ===;")))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            TestingLibraryError::Javascript { ref name, .. } if name == "SyntaxError"
+        ));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_invalid_regex_option_error(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("by_role_options.html").await?;
+
+        let error = screen
+            .get(By::role("button").name("/[/"))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, TestingLibraryError::InvalidRegex(_)));
+
+        let error = screen
+            .get(By::role("button").description("/[/").disabled(true))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, TestingLibraryError::InvalidRegex(_)));
+
+        Ok(())
+    })
+}
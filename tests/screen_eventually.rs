@@ -0,0 +1,97 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use std::time::Duration;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, expect, By, TestingLibraryError, WaitForOptions};
+
+#[rstest]
+fn test_expect_eventually(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("eventually.html").await?;
+
+        let status = screen.get(By::role("status")).await?;
+        expect(&status)
+            .eventually()
+            .to_have_text_content("Saved")
+            .await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_expect_eventually_reports_last_failure(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("eventually.html").await?;
+
+        let status = screen.get(By::role("status")).await?;
+        let result = expect(&status)
+            .eventually_with(WaitForOptions::new().timeout(Duration::from_millis(100)))
+            .to_have_text_content("Saved")
+            .await;
+
+        match result {
+            Err(TestingLibraryError::WaitTimeout { last_error, .. }) => {
+                assert!(
+                    matches!(*last_error, TestingLibraryError::AssertionFailed(ref failure) if failure.message.contains("Saving..."))
+                );
+            }
+            other => panic!("expected a wait timeout, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_screen_eventually(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("eventually.html").await?;
+
+        screen
+            .eventually(None)
+            .assert(|screen| async move {
+                let status = screen.get(By::role("status")).await?;
+                screen.expect(&status).to_have_text_content("Saved").await
+            })
+            .await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_eventually_honors_async_util_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("eventually.html").await?;
+        let screen = screen.configure(configure::Options::new().with_async_util_timeout(100));
+
+        // The status changes after 300ms, later than the configured timeout
+        let status = screen.get(By::role("status")).await?;
+        let result = screen
+            .expect(&status)
+            .eventually()
+            .to_have_text_content("Saved")
+            .await;
+        assert!(matches!(
+            result,
+            Err(TestingLibraryError::WaitTimeout { .. })
+        ));
+
+        let result = screen
+            .eventually(Some(Duration::from_millis(100)))
+            .assert(|screen| async move {
+                let status = screen.get(By::role("status")).await?;
+                expect(&status).to_have_text_content("Saved").await
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(TestingLibraryError::WaitTimeout { .. })
+        ));
+
+        Ok(())
+    })
+}
@@ -0,0 +1,162 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use serde_json::json;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, expect, By, TestingLibraryError, TextMatch};
+
+#[rstest]
+fn test_expect_matchers(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("expect.html").await?;
+
+        let title = screen.get(By::role("heading")).await?;
+        expect(&title).to_be_in_the_document().await?;
+        expect(&title).to_be_visible().await?;
+        expect(&title).to_have_text_content("Order summary").await?;
+        expect(&title)
+            .to_have_text_content(TextMatch::Regex("/^order/i".to_string()))
+            .await?;
+        expect(&title).to_have_class("large title").await?;
+        expect(&title)
+            .to_have_attribute("data-state", Some("ready"))
+            .await?;
+        expect(&title)
+            .not()
+            .to_have_attribute("hidden", None)
+            .await?;
+
+        let note = screen.get(By::text("Hidden note")).await?;
+        expect(&note).not().to_be_visible().await?;
+
+        expect(&screen.get(By::label_text("Name")).await?)
+            .to_have_value("Ferris")
+            .await?;
+        expect(&screen.get(By::label_text("Quantity")).await?)
+            .to_have_value(3)
+            .await?;
+        expect(&screen.get(By::label_text("Sizes")).await?)
+            .to_have_value(json!(["s", "l"]))
+            .await?;
+
+        expect(&screen.get(By::label_text("Gift wrap")).await?)
+            .to_be_checked()
+            .await?;
+        expect(&screen.get(By::role("switch")).await?)
+            .not()
+            .to_be_checked()
+            .await?;
+
+        let submit = screen.get(By::role("button").name("Submit")).await?;
+        expect(&submit).to_be_disabled().await?;
+        expect(&submit).not().to_have_focus().await?;
+
+        let name = screen.get(By::label_text("Name")).await?;
+        name.focus().await?;
+        expect(&name).to_have_focus().await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_expect_failure_message(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("expect.html").await?;
+
+        let title = screen.get(By::role("heading")).await?;
+        let result = expect(&title).to_have_text_content("Invoice").await;
+
+        match result {
+            Err(TestingLibraryError::AssertionFailed(failure)) => {
+                assert_eq!(failure.assertion, "expect(element).toHaveTextContent()");
+                assert!(failure.message.contains("\"Invoice\""));
+                assert!(failure.message.contains("\"Order summary\""));
+                let element = failure.element.as_deref().unwrap_or_default();
+                assert!(element.starts_with("<h1"));
+                assert!(element.contains("id=\"title\""));
+            }
+            other => panic!("expected an assertion failure, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_screen_expect_passes(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("expect.html")
+            .await?
+            .configure(configure::Options::new().with_debug_print_limit(10));
+
+        // Passing assertions resolve to `Ok`, the element is only printed on failure
+        let title = screen.get(By::role("heading")).await?;
+        let result = screen
+            .expect(&title)
+            .to_have_text_content("Order summary")
+            .await;
+        assert!(
+            result.is_ok(),
+            "expected the assertion to pass, got {result:?}"
+        );
+        screen
+            .expect(&title)
+            .not()
+            .to_have_text_content("Invoice")
+            .await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_expect_failure_respects_debug_print_limit(
+    test_harness: TestHarness,
+) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("expect.html")
+            .await?
+            .configure(configure::Options::new().with_debug_print_limit(10));
+
+        let title = screen.get(By::role("heading")).await?;
+        let result = screen.expect(&title).to_have_text_content("Invoice").await;
+
+        match result {
+            Err(TestingLibraryError::AssertionFailed(failure)) => {
+                let element = failure.element.as_deref().unwrap_or_default();
+                assert!(element.starts_with("<h1"));
+                assert!(element.ends_with("..."));
+                assert_eq!(element.chars().count(), 13);
+            }
+            other => panic!("expected an assertion failure, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_expect_removed_element(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("expect.html").await?;
+
+        let title = screen.get(By::role("heading")).await?;
+        screen
+            .get(By::role("button").name("Remove title"))
+            .await?
+            .click()
+            .await?;
+
+        expect(&title).not().to_be_in_the_document().await?;
+        assert!(matches!(
+            expect(&title).to_be_in_the_document().await,
+            Err(TestingLibraryError::AssertionFailed(_))
+        ));
+
+        Ok(())
+    })
+}
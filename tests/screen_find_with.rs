@@ -0,0 +1,108 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use std::time::Duration;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{
+    By, MutationObserverOptions, TestingLibraryError, WaitOptions,
+};
+
+#[rstest]
+fn test_find_with_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("find_with.html").await?;
+
+        // The report shows up after 1500ms, later than the default 1000ms timeout
+        let result = screen.find(By::text("Report generated")).await;
+        assert!(matches!(result, Err(TestingLibraryError::FindTimeout(_))));
+
+        let report = screen
+            .find_with(
+                By::text("Report generated"),
+                WaitOptions::new().timeout(Duration::from_secs(5)),
+            )
+            .await?;
+        assert_id(&report, "report").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_find_all_with_options(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("find_with.html").await?;
+
+        let paragraphs = screen
+            .find_all_with(
+                By::text("/^Report/"),
+                WaitOptions::new()
+                    .timeout(Duration::from_secs(5))
+                    .interval(Duration::from_millis(100))
+                    .mutation_observer_options(
+                        MutationObserverOptions::new()
+                            .subtree(true)
+                            .child_list(true),
+                    ),
+            )
+            .await?;
+        assert_count(&paragraphs, 2)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_find_with_raises_script_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("find_with.html").await?;
+        let driver = test_harness.driver();
+
+        // A script timeout shorter than the wait would abort the find script
+        driver.set_script_timeout(Duration::from_secs(1)).await?;
+
+        let report = screen
+            .find_with(
+                By::text("Report generated"),
+                WaitOptions::new().timeout(Duration::from_secs(5)),
+            )
+            .await?;
+        assert_id(&report, "report").await?;
+
+        // The script timeout is restored once the find is done
+        assert_eq!(
+            driver.get_timeouts().await?.script(),
+            Some(Duration::from_secs(1))
+        );
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_find_with_raises_short_script_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("find_with.html").await?;
+        let driver = test_harness.driver();
+
+        // Even a one second wait outlasts this script timeout
+        driver
+            .set_script_timeout(Duration::from_millis(200))
+            .await?;
+
+        let result = screen
+            .find_with(
+                By::text("Report generated"),
+                WaitOptions::new().timeout(Duration::from_secs(1)),
+            )
+            .await;
+        assert!(matches!(result, Err(TestingLibraryError::FindTimeout(_))));
+        assert_eq!(
+            driver.get_timeouts().await?.script(),
+            Some(Duration::from_millis(200))
+        );
+
+        Ok(())
+    })
+}
@@ -0,0 +1,96 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use serde_json::json;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, DataTransfer, Event, KeyboardEventInit};
+
+#[rstest]
+fn test_fire_typed_events(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("fire_event.html").await?;
+
+        let panel = screen.get(By::text("Panel")).await?;
+        screen
+            .fire_event(
+                &panel,
+                Event::TransitionEnd {
+                    property_name: "opacity".to_string(),
+                },
+            )
+            .await?;
+        screen.get(By::text("transition opacity")).await?;
+
+        let feed = screen.get(By::label_text("Feed")).await?;
+        screen
+            .fire_event(&feed, Event::Scroll { top: 100.0 })
+            .await?;
+        screen.get(By::text("scrolled 100")).await?;
+
+        let search = screen.get(By::label_text("Search")).await?;
+        screen
+            .fire_event(&search, Event::Change("rust".to_string()))
+            .await?;
+        screen.get(By::text("changed rust")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_fire_event_cancelled(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("fire_event.html").await?;
+
+        let search = screen.get(By::label_text("Search")).await?;
+        let not_cancelled = screen
+            .fire_event(&search, Event::KeyDown(KeyboardEventInit::new("Enter")))
+            .await?;
+        assert!(!not_cancelled);
+        screen.get(By::text("enter")).await?;
+
+        let not_cancelled = screen
+            .fire_event(&search, Event::KeyDown(KeyboardEventInit::new("a")))
+            .await?;
+        assert!(not_cancelled);
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_fire_drag_event_with_data_transfer(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("fire_event.html").await?;
+
+        let dropzone = screen.get(By::text("Drop here")).await?;
+        screen
+            .fire_event(
+                &dropzone,
+                Event::DragOver(DataTransfer::new().data("text/plain", "card-1")),
+            )
+            .await?;
+        screen.get(By::text("over card-1")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_fire_custom_event(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("fire_event.html").await?;
+
+        let card = screen.get(By::text("Card")).await?;
+        screen
+            .fire_event(
+                &card,
+                Event::custom("card-selected", json!({ "detail": { "id": 42 } }))?,
+            )
+            .await?;
+        screen.get(By::text("selected 42")).await?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,82 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, TestingLibraryError};
+
+#[rstest]
+fn test_within_frame(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("iframe_outer.html").await?;
+
+        assert!(matches!(
+            screen.get(By::role("button").name("Just A Button")).await,
+            Err(TestingLibraryError::NoElementFound(_))
+        ));
+
+        let frame = screen.within_frame(By::title("Inner frame")).await?;
+        let button = frame.get(By::role("button")).await?;
+        assert_id(&button, "iframe_button").await?;
+        frame.find(By::text("Just A Button")).await?;
+
+        // The parent context is restored after each query
+        let root_button = screen.get(By::role("button")).await?;
+        assert_id(&root_button, "root_button").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_within_frame_element(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("iframe_outer.html").await?;
+
+        let iframe = test_harness
+            .driver()
+            .find(thirtyfour::By::Id("iframe"))
+            .await?;
+        let frame = screen.within_frame(iframe).await?;
+        frame.user().click(By::role("button")).await?;
+        assert_count(&frame.get_all(By::role("button")).await?, 1)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_within_frame_expect(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("iframe_outer.html").await?;
+
+        let frame = screen.within_frame(By::title("Inner frame")).await?;
+        let button = frame.get(By::role("button")).await?;
+        frame.expect(&button).to_be_visible().await?;
+        frame
+            .expect(&button)
+            .to_have_text_content("Just A Button")
+            .await?;
+        frame.expect(&button).not().to_have_focus().await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_query_all_frames(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("iframe_outer.html").await?;
+
+        let buttons = screen.query_all_frames(By::role("button")).await?;
+        assert_count(&buttons, 2)?;
+        assert_id(&buttons[0], "root_button").await?;
+
+        let buttons = screen
+            .query_all_frames(By::role("button").name("Just A Button"))
+            .await?;
+        assert_count(&buttons, 1)?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,73 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, By, PrettyDomOptions};
+
+#[rstest]
+fn test_pretty_dom(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("pretty_dom.html").await?;
+
+        let dom = screen.pretty_dom(None, PrettyDomOptions::new()).await?;
+        assert!(dom.starts_with("<body>"));
+        assert!(dom.contains("Hello there"));
+        assert!(!dom.contains("navigation"));
+        assert!(!dom.contains("window.loaded"));
+
+        let section = screen.get(By::role("region")).await?;
+        let dom = screen
+            .pretty_dom(Some(&section), PrettyDomOptions::new())
+            .await?;
+        assert!(dom.starts_with("<section"));
+        assert!(!dom.contains("Home"));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_pretty_dom_options(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("pretty_dom.html").await?;
+
+        let dom = screen
+            .pretty_dom(None, PrettyDomOptions::new().max_length(10))
+            .await?;
+        assert_eq!(dom, "<body>\n  <...");
+
+        let dom = screen
+            .pretty_dom(
+                None,
+                PrettyDomOptions::new().filter_node("return node.nodeType === Node.COMMENT_NODE || node.nodeType === Node.ELEMENT_NODE;"),
+            )
+            .await?;
+        assert!(dom.contains("navigation"));
+        assert!(!dom.contains("Hello there"));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_pretty_dom_within_and_print_limit(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("pretty_dom.html").await?;
+
+        let nav = screen.get(By::role("navigation")).await?;
+        let dom = screen
+            .within(nav)
+            .pretty_dom(None, PrettyDomOptions::new())
+            .await?;
+        assert!(dom.starts_with("<nav"));
+        assert!(!dom.contains("Hello there"));
+
+        let screen = screen.configure(configure::Options::new().with_debug_print_limit(5));
+        let dom = screen.pretty_dom(None, PrettyDomOptions::new()).await?;
+        assert_eq!(dom, "<body...");
+        screen.debug().await?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,66 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, RolesOptions};
+
+#[rstest]
+fn test_roles(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("roles.html").await?;
+
+        let roles = screen.roles().await?;
+        assert_count(&roles["link"], 2)?;
+        assert_id(&roles["link"][0], "home").await?;
+        assert_id(&roles["heading"][0], "title").await?;
+        assert_id(&roles["navigation"][0], "menu").await?;
+        assert_count(&roles["button"], 1)?;
+
+        let roles = screen.roles_with(RolesOptions::new().hidden(true)).await?;
+        assert_count(&roles["button"], 2)?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_roles_within(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("roles.html").await?;
+
+        let nav = screen.get(By::role("navigation")).await?;
+        let roles = screen.within(nav).roles().await?;
+        assert!(roles.contains_key("link"));
+        assert!(!roles.contains_key("button"));
+        assert!(!roles.contains_key("heading"));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_log_roles(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("roles.html").await?;
+
+        let log = screen.log_roles().await?;
+        assert!(log.contains("button:"));
+        assert!(log.contains("Name \"Save\":"));
+        assert!(!log.contains("Name \"Secret\":"));
+
+        let log = screen
+            .log_roles_with(RolesOptions::new().hidden(true))
+            .await?;
+        assert!(log.contains("Name \"Secret\":"));
+
+        let secret = screen
+            .get(By::role("button").name("Secret").hidden(true))
+            .await?;
+        assert!(screen.is_inaccessible(&secret).await?);
+        let save = screen.get(By::role("button").name("Save")).await?;
+        assert!(!screen.is_inaccessible(&save).await?);
+
+        Ok(())
+    })
+}
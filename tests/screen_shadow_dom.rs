@@ -0,0 +1,86 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, TestingLibraryError, TestingLibraryQueryable};
+
+#[rstest]
+fn test_pierce_shadow(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("shadow_dom.html").await?;
+
+        // Testing Library stops at shadow roots
+        assert_count(&screen.query_all(By::role("button")).await?, 1)?;
+
+        let screen = screen.pierce_shadow(true);
+        assert_count(&screen.query_all(By::role("button")).await?, 3)?;
+
+        let save = screen.get(By::role("button").name("Save")).await?;
+        assert_id(&save, "shadow-button").await?;
+        screen.find(By::role("button").name("Delete")).await?;
+        assert_none(screen.query(By::text("Missing")).await?)?;
+
+        assert!(matches!(
+            screen.get(By::role("button")).await,
+            Err(TestingLibraryError::MultipleElementsFound(_))
+        ));
+        assert!(matches!(
+            screen.get(By::role("link")).await,
+            Err(TestingLibraryError::NoElementFound(_))
+        ));
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_within_shadow_root(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("shadow_dom.html").await?;
+
+        let card = test_harness
+            .driver()
+            .find(thirtyfour::By::Id("card-host"))
+            .await?;
+        let card_screen = screen.within_shadow_root(&card).await?;
+        assert_id(&card_screen.get(By::role("heading")).await?, "card-title").await?;
+
+        // The nested my-button shadow root is only searched when piercing
+        assert_none(card_screen.query(By::role("button")).await?)?;
+        let delete = card_screen
+            .pierce_shadow(true)
+            .get(By::role("button"))
+            .await?;
+        assert_id(&delete, "shadow-button").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_pierce_shadow_tl_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness
+            .screen_for_page("shadow_dom.html")
+            .await?
+            .pierce_shadow(true);
+
+        // Matches inside shadow roots are returned as they are, not selected again from the document
+        let delete = screen
+            .tl_query(By::role("button").name("Delete"))
+            .and_displayed()
+            .single()
+            .await?;
+        assert_id(&delete, "shadow-button").await?;
+
+        let buttons = screen
+            .tl_query(By::role("button"))
+            .nowait()
+            .all_from_selector()
+            .await?;
+        assert_count(&buttons, 3)?;
+
+        Ok(())
+    })
+}
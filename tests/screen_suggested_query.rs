@@ -0,0 +1,57 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{By, QueryMethod, TextMatch};
+
+#[rstest]
+fn test_suggested_query(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("suggested_query.html").await?;
+        let driver = test_harness.driver();
+
+        let save = driver.find(thirtyfour::By::Id("save")).await?;
+        let by = screen.suggested_query(&save, None).await?.unwrap();
+        assert!(matches!(by, By::Role(ref role, Some(_)) if role.text_value() == "button"));
+        assert_id(&screen.get(by).await?, "save").await?;
+
+        let email = driver.find(thirtyfour::By::Id("email")).await?;
+        let by = screen.suggested_query(&email, None).await?.unwrap();
+        assert_id(&screen.get(by).await?, "email").await?;
+
+        let plain = driver.find(thirtyfour::By::Id("plain")).await?;
+        let by = screen.suggested_query(&plain, None).await?.unwrap();
+        assert!(matches!(by, By::Text(ref text, _) if text.is_regex()));
+        assert_id(&screen.get(by).await?, "plain").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_suggested_query_with_method(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("suggested_query.html").await?;
+        let driver = test_harness.driver();
+
+        let save = driver.find(thirtyfour::By::Id("save")).await?;
+        let by = screen
+            .suggested_query(&save, Some(QueryMethod::TestId))
+            .await?
+            .unwrap();
+        assert!(matches!(by, By::TestId(TextMatch::String(ref id), _) if id == "save-button"));
+
+        let search = driver.find(thirtyfour::By::Id("search")).await?;
+        let by = screen
+            .suggested_query(&search, Some(QueryMethod::PlaceholderText))
+            .await?
+            .unwrap();
+        assert_id(&screen.get(by).await?, "search").await?;
+
+        let empty = driver.find(thirtyfour::By::Id("empty")).await?;
+        assert!(screen.suggested_query(&empty, None).await?.is_none());
+
+        Ok(())
+    })
+}
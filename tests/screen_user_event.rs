@@ -0,0 +1,118 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::By;
+
+// 1. Pointer Tests
+
+#[rstest]
+fn test_click(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+        let user = screen.user();
+
+        user.click(By::role("button").name("Count")).await?;
+        let button = screen.get(By::role("button").name("Count")).await?;
+        user.click(&button).await?;
+
+        screen.get(By::text("Clicks: 2")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_dbl_click(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+
+        screen
+            .user()
+            .dbl_click(By::role("button").name("Double"))
+            .await?;
+        screen.get(By::text("Double clicked")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_hover_unhover(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+        let user = screen.user();
+
+        user.hover(By::text("Help")).await?;
+        screen.get(By::role("tooltip")).await?;
+
+        user.unhover(By::text("Help")).await?;
+        assert_none(screen.query(By::role("tooltip")).await?)?;
+
+        Ok(())
+    })
+}
+
+// 2. Keyboard Tests
+
+#[rstest]
+fn test_clear_and_type_text(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+        let user = screen.user();
+
+        user.clear(By::label_text("Name")).await?;
+        user.type_text(By::label_text("Name"), "Adrien").await?;
+
+        let input = screen.get(By::label_text("Name")).await?;
+        assert_eq!(input.prop("value").await?, Some("Adrien".to_string()));
+        screen.get(By::text("Adrien")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_tab_keyboard_and_paste(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+        let user = screen.user();
+
+        user.click(By::label_text("First")).await?;
+        user.keyboard("abc").await?;
+        user.tab().await?;
+        user.paste("pasted").await?;
+        user.shift_tab().await?;
+        user.keyboard("{Backspace}").await?;
+
+        let first = screen.get(By::label_text("First")).await?;
+        assert_eq!(first.prop("value").await?, Some("ab".to_string()));
+        let second = screen.get(By::label_text("Second")).await?;
+        assert_eq!(second.prop("value").await?, Some("pasted".to_string()));
+
+        Ok(())
+    })
+}
+
+// 3. Select Tests
+
+#[rstest]
+fn test_select_and_deselect_options(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("user_event.html").await?;
+        let user = screen.user();
+
+        user.select_options(By::label_text("Fruits"), &["apple", "Cherry"])
+            .await?;
+        let selected = screen.get_all(By::role("option").selected(true)).await?;
+        assert_count(&selected, 2)?;
+
+        user.deselect_options(By::label_text("Fruits"), &["apple"])
+            .await?;
+        let selected = screen.get(By::role("option").selected(true)).await?;
+        assert_text(&selected, "Cherry").await?;
+
+        Ok(())
+    })
+}
@@ -0,0 +1,150 @@
+mod common;
+use common::*;
+use rstest::rstest;
+use std::time::Duration;
+use thirtyfour::prelude::*;
+use thirtyfour::support::block_on;
+use thirtyfour_testing_library_ext::{configure, By, TestingLibraryError, WaitForOptions};
+
+// 1. wait_for Tests
+
+#[rstest]
+fn test_wait_for(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+
+        let report = screen
+            .wait_for(|screen| async move { screen.get(By::text("Report ready")).await })
+            .await?;
+        assert_id(&report, "report").await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_wait_for_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+
+        let result = screen
+            .wait_for_with(
+                |screen| async move { screen.get(By::text("Never shown")).await },
+                WaitForOptions::new()
+                    .timeout(Duration::from_millis(200))
+                    .interval(Duration::from_millis(20)),
+            )
+            .await;
+
+        match result {
+            Err(TestingLibraryError::WaitTimeout { last_error, .. }) => {
+                assert!(matches!(
+                    *last_error,
+                    TestingLibraryError::NoElementFound(_)
+                ));
+            }
+            other => panic!("Expected a WaitTimeout error, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_wait_for_on_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+
+        let result = screen
+            .wait_for_with(
+                |screen| async move { screen.get(By::text("Never shown")).await },
+                WaitForOptions::new()
+                    .timeout(Duration::from_millis(100))
+                    .on_timeout(|_| TestingLibraryError::Javascript {
+                        name: "Error".to_string(),
+                        message: "Report never showed up".to_string(),
+                    }),
+            )
+            .await;
+
+        match result {
+            Err(TestingLibraryError::Javascript { message, .. }) => {
+                assert_eq!(message, "Report never showed up");
+            }
+            other => panic!("Expected the on_timeout error, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_wait_for_honors_async_util_timeout(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+        let screen = screen.configure(configure::Options::new().with_async_util_timeout(100));
+
+        // The report shows up after 300ms, later than the configured timeout
+        let result = screen
+            .wait_for(|screen| async move { screen.get(By::text("Report ready")).await })
+            .await;
+        assert!(matches!(
+            result,
+            Err(TestingLibraryError::WaitTimeout { .. })
+        ));
+
+        Ok(())
+    })
+}
+
+// 2. wait_for_element_to_be_removed Tests
+
+#[rstest]
+fn test_wait_for_element_to_be_removed(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+
+        screen
+            .wait_for_element_to_be_removed(By::role("progressbar"))
+            .await?;
+        assert_none(screen.query(By::role("progressbar")).await?)?;
+        screen.get(By::text("Report ready")).await?;
+
+        Ok(())
+    })
+}
+
+#[rstest]
+fn test_wait_for_element_to_be_removed_errors(test_harness: TestHarness) -> WebDriverResult<()> {
+    block_on(async {
+        let screen = test_harness.screen_for_page("wait_for.html").await?;
+
+        // Elements must be present when starting to wait
+        let result = screen
+            .wait_for_element_to_be_removed(By::text("Never shown"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(TestingLibraryError::NoElementFound(_))
+        ));
+
+        // The toast is never removed
+        let result = screen
+            .wait_for_element_to_be_removed_with(
+                By::role("status"),
+                WaitForOptions::new().timeout(Duration::from_millis(100)),
+            )
+            .await;
+        match result {
+            Err(TestingLibraryError::WaitTimeout { last_error, .. }) => {
+                assert!(matches!(
+                    *last_error,
+                    TestingLibraryError::ElementsStillPresent(_)
+                ));
+            }
+            other => panic!("Expected a WaitTimeout error, got {other:?}"),
+        }
+
+        Ok(())
+    })
+}